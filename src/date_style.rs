@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+
+/// The order of the day, the month and the year in `FirstDate::date_string`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateOrder {
+    /// Month, day, year, for example "05/23/2025".
+    #[default]
+    Mdy,
+    /// Day, month, year, for example "23.05.2025".
+    Dmy,
+    /// Year, month, day, for example "2025-05-23".
+    Ymd,
+}
+
+/// The clock used for the hour in `FirstDate::date_string`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockFormat {
+    /// 12 to 11 followed by AM or PM, midnight is "12:00:00 AM".
+    #[default]
+    TwelveHour,
+    /// 0 to 23.
+    TwentyFourHour,
+}
+
+/// How `FirstDate::date_string` writes the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum YearFormat {
+    /// The year of era, followed by " BC" for year 0 and earlier, for example "2025" or "44 BC".
+    #[default]
+    Era,
+    /// The ISO 8601 year, four digits with a sign for years before 0 or after 9999, for example "0043", "-0043" (44 BC) or "+10000".
+    Iso,
+}
+
+/// The options of `FirstDate::date_string`. The default is the `US` preset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateStyle {
+    pub order: DateOrder,
+    pub year_format: YearFormat,
+    /// Written between the day, the month and the year. `Cow::Borrowed` for a literal like the presets, `Cow::Owned` for text built at runtime.
+    pub date_separator: Cow<'static, str>,
+    /// Written between the hour, the minutes and the seconds.
+    pub time_separator: Cow<'static, str>,
+    pub clock: ClockFormat,
+    /// Pads the month, the day and the hour with a zero ("05/03/2025 09:05:00" instead of "5/3/2025 9:05:00"). The minutes and the seconds are always padded.
+    pub zero_pad: bool,
+    /// Writes the milliseconds after the seconds, for example "03:46:48.447".
+    pub show_milliseconds: bool,
+    /// Writes the offset from UTC (including daylight savings time) at the end, for example "-05:00".
+    pub show_offset: bool,
+}
+
+impl DateStyle {
+    /// For example "05/23/2025 03:46:48 AM".
+    pub const US: DateStyle = DateStyle {
+        order: DateOrder::Mdy,
+        year_format: YearFormat::Era,
+        date_separator: Cow::Borrowed("/"),
+        time_separator: Cow::Borrowed(":"),
+        clock: ClockFormat::TwelveHour,
+        zero_pad: true,
+        show_milliseconds: false,
+        show_offset: false,
+    };
+
+    /// For example "23.05.2025 03:46:48".
+    pub const EUROPEAN: DateStyle = DateStyle {
+        order: DateOrder::Dmy,
+        year_format: YearFormat::Era,
+        date_separator: Cow::Borrowed("."),
+        time_separator: Cow::Borrowed(":"),
+        clock: ClockFormat::TwentyFourHour,
+        zero_pad: true,
+        show_milliseconds: false,
+        show_offset: false,
+    };
+
+    /// For example "2025-05-23 03:46:48 +00:00".
+    pub const ISO: DateStyle = DateStyle {
+        order: DateOrder::Ymd,
+        year_format: YearFormat::Iso,
+        date_separator: Cow::Borrowed("-"),
+        time_separator: Cow::Borrowed(":"),
+        clock: ClockFormat::TwentyFourHour,
+        zero_pad: true,
+        show_milliseconds: false,
+        show_offset: true,
+    };
+}
+
+impl Default for DateStyle {
+    fn default() -> Self {
+        DateStyle::US
+    }
+}
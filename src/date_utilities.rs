@@ -1,16 +1,20 @@
+use crate::error::FirstDateError;
+use crate::instant::Instant;
+use crate::tzif::{LocalTimeType, TimeZoneInfo};
+use crate::utc_offset::UtcOffset;
 use crate::FirstDate;
-pub use std::fs::{self, File};
-use std::io::{BufRead, BufReader, Error, ErrorKind};
-use std::process::Command;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-const MONTHS_IN_YEAR: i16 = 12;
-const EPOCH_YEAR: f32 = 1970.0;
-const EPOCH_DAY: isize = 86400;
-const DAYS_IN_YEAR_WITH_LEAP: f32 = 365.2425;
+pub const EPOCH_DAY: isize = 86400;
 const MILLISECONDS_IN_SECOND: isize = 1000;
+pub const MILLISECONDS_IN_MINUTE: i64 = 60 * 1000;
+pub const MILLISECONDS_IN_HOUR: i64 = 60 * MILLISECONDS_IN_MINUTE;
+pub const MILLISECONDS_IN_DAY: i64 = 24 * MILLISECONDS_IN_HOUR;
 
-const MONTHS: [(&str, i16); 12] = [
+pub const MONTHS: [(&str, i16); 12] = [
     ("January", 31),
     ("February", 28),
     ("March", 31),
@@ -25,25 +29,7 @@ const MONTHS: [(&str, i16); 12] = [
     ("December", 31),
 ];
 
-pub enum AddEnum {
-    SameUnit(u16, Option<String>),
-    NextUnit(u16, u16, Option<String>),
-}
-
-struct DstDateRange {
-    day: i8,
-    month: String,
-    year: i16,
-    hour: i16,
-    minute: i16,
-    second: i16,
-}
-
-struct DstRange {
-    end: DstDateRange,
-    start: DstDateRange,
-}
-
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimeUnit {
     Day,
     Year,
@@ -54,295 +40,256 @@ pub enum TimeUnit {
     Millisecond,
 }
 
-pub fn is_dst(date: &FirstDate) -> Result<bool, Error> {
-    let args: Vec<String> = Vec::from([
-        String::from("-V"),
-        String::from("-c"),
-        String::from(format!("{},{}", date.year, date.year + 1)),
-        date.timezone_name.clone(),
-    ]);
-
-    let command_result = Command::new("zdump").args(args).output()?;
-    let string_output_result = String::from_utf8(command_result.stdout);
-    let raw_string_output = match string_output_result {
-        Ok(string_data) => string_data,
-        Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-    };
-
-    let sliced_output: Vec<&str> = raw_string_output.split("\n").collect();
-    let dst_range = parse_zdump(sliced_output);
+/// The range of years a `FirstDate` can represent. Years use astronomical numbering, so year 0 is 1 BC and year -999999 is 1000000 BC.
+pub const MIN_YEAR: i64 = -999_999;
+pub const MAX_YEAR: i64 = 999_999;
+
+/// The era of a date. `FirstDate::year` uses astronomical numbering (year 0 is 1 BC, year -1 is 2 BC), the era and `year_of_era` give the traditional numbering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Era {
+    /// Before Christ, astronomical years 0 and earlier.
+    Bc,
+    /// Anno Domini, astronomical years 1 and later.
+    Ad,
+}
 
-    let january_index = 1;
-    let february_index = 2;
-    let march_index = 3;
-    let novemember_index = 11;
-    let december_index = 12;
-    if date.month == january_index || date.month == february_index || date.month == december_index {
-        return Ok(false);
-    }
-    if date.month == march_index {
-        if date.day <= dst_range.start.day as u8 {
-            return Ok(false);
-        }
-        if date.day == dst_range.start.day as u8
-            && (date.hour <= dst_range.start.hour as u8
-                || date.minute <= dst_range.start.minute as u8
-                || date.second <= dst_range.start.second as u8)
-        {
-            return Ok(false);
-        }
+/// Splits an astronomical year into its era and year of era, for example year 0 is (BC, 1) and year -43 is (BC, 44).
+pub fn era_of(year: i64) -> (Era, i64) {
+    if year <= 0 {
+        (Era::Bc, 1 - year)
+    } else {
+        (Era::Ad, year)
     }
+}
 
-    if date.month == novemember_index {
-        if date.day <= dst_range.end.day as u8 {
-            return Ok(false);
-        }
-        if date.day == dst_range.end.day as u8
-            && (date.hour >= dst_range.end.hour as u8
-                || date.minute >= dst_range.end.minute as u8
-                || date.second >= dst_range.end.second as u8)
-        {
-            return Ok(false);
-        }
-    }
-    Ok(true)
+/// What to do when adding months or years lands on a day that does not exist in the new month, for example January 31st + 1 month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonthOverflow {
+    /// Use the last day of the new month (February 28th, or 29th in leap years).
+    #[default]
+    Clamp,
+    /// Carry the extra days into the following month (March 3rd, or 2nd in leap years).
+    Overflow,
+    /// Return `FirstDateError::InvalidField` for the day.
+    Error,
+}
+
+/// The directory the TZif files are read from. The tests read the copies in `resources/tzif` so they don't depend on the zone data installed on the machine.
+#[cfg(not(test))]
+const TIMEZONE_DIRECTORY: &str = crate::tzif::ZONEINFO_DIRECTORY;
+#[cfg(test)]
+const TIMEZONE_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/resources/tzif");
+
+/// Reads the compiled zone data of a timezone name, see `TimeZoneInfo::for_zone`.
+pub fn timezone_info(timezone_name: &str) -> Result<TimeZoneInfo, FirstDateError> {
+    TimeZoneInfo::for_zone_in(Path::new(TIMEZONE_DIRECTORY), timezone_name)
+        .map_err(|error| timezone_data_error(timezone_name, error))
+}
+
+/// The local time type of the zone for a wall clock time, in milliseconds since Jan 1 1970 ignoring the offset. A time skipped or repeated by a transition gets one of the two offsets around it.
+pub fn local_time_type_at_wall_clock(zone: &TimeZoneInfo, local_millis: i64) -> LocalTimeType {
+    let local_seconds = local_millis.div_euclid(MILLISECONDS_IN_SECOND as i64);
+    let guess = zone.utc_offset_at(local_seconds) as i64;
+    zone.local_time_type_at(local_seconds - guess)
+}
+
+/// The UTC offset of a local time type, an offset out of range in the zone data is reported as `FirstDateError::MissingTimezoneData`.
+pub fn utc_offset_of(
+    local_time_type: &LocalTimeType,
+    timezone_name: &str,
+) -> Result<UtcOffset, FirstDateError> {
+    UtcOffset::from_seconds(local_time_type.utc_offset).ok_or_else(|| {
+        FirstDateError::MissingTimezoneData(format!(
+            "Invalid UTC offset in the zone data of {}",
+            timezone_name
+        ))
+    })
 }
 
-fn parse_zdump(zdump_data: Vec<&str>) -> DstRange {
-    let filtered_formatted_data: Vec<&str> = zdump_data
-        .into_iter()
-        .filter(|line| line.contains("isdst=1"))
-        .map(|line| {
-            let formatted_line: Vec<&str> = line.split("=").collect();
-            return formatted_line[1].trim();
-        })
-        .collect();
-
-    let mut range = DstRange {
-        start: DstDateRange {
-            day: -1,
-            hour: -1,
-            minute: -1,
-            second: -1,
-            year: -1,
-            month: String::from(""),
-        },
-        end: DstDateRange {
-            day: -1,
-            hour: -1,
-            minute: -1,
-            second: -1,
-            year: -1,
-            month: String::from(""),
-        },
+/// A missing TZif file means the timezone does not exist, unless the whole zoneinfo directory is missing.
+fn timezone_data_error(timezone_name: &str, error: Error) -> FirstDateError {
+    let is_unknown_timezone = match error.kind() {
+        ErrorKind::NotFound => Path::new(TIMEZONE_DIRECTORY).is_dir(),
+        ErrorKind::InvalidInput => true,
+        _ => false,
     };
 
-    let start = filtered_formatted_data[0];
-    let end = filtered_formatted_data[1];
-    if start.contains("Mar") {
-        range.start.month = String::from("March");
-        let start_time_data = extract_day_and_time(&start);
-        range.start.day = start_time_data.0;
-        range.start.hour = start_time_data.1;
-        range.start.minute = start_time_data.2;
-        range.start.second = start_time_data.3;
-        range.start.year = start_time_data.4;
-    }
-    if end.contains("Nov") {
-        range.end.month = String::from("November");
-        let end_time_data = extract_day_and_time(&end);
-        range.end.day = end_time_data.0;
-        range.end.hour = end_time_data.1;
-        range.end.minute = end_time_data.2;
-        range.end.second = end_time_data.3;
-        range.end.year = end_time_data.4;
+    if is_unknown_timezone {
+        FirstDateError::UnknownTimezone(String::from(timezone_name))
+    } else {
+        FirstDateError::MissingTimezoneData(error.to_string())
     }
+}
 
-    range
+/// The number of seconds since the Unix epoch represented by the date, taking the timezone offset into account.
+pub fn epoch_seconds(date: &FirstDate) -> i64 {
+    instant_of(date)
+        .millis()
+        .div_euclid(MILLISECONDS_IN_SECOND as i64)
 }
 
-// (day, hour, minute, second, year)
-fn extract_day_and_time(data: &str) -> (i8, i16, i16, i16, i16) {
-    let mut answer = (-1, -1, -1, -1, -1);
-    let mut char_iterator = data.chars();
-    let mut current_char: char = ' ';
-    let mut current_char_option: Option<char> = None;
-    loop {
-        current_char_option = char_iterator.next();
-        if current_char_option == None {
-            break;
-        }
+/// The number of milliseconds since Jan 1 1970 shown by the date's fields, ignoring the timezone offset.
+pub fn local_epoch_millis(date: &FirstDate) -> i64 {
+    let days = days_from_civil(date.year as i64, date.month, date.day);
+    days * MILLISECONDS_IN_DAY
+        + date.hour as i64 * MILLISECONDS_IN_HOUR
+        + date.minute as i64 * MILLISECONDS_IN_MINUTE
+        + date.second as i64 * MILLISECONDS_IN_SECOND as i64
+        + date.millisecond as i64
+}
 
-        current_char = current_char_option.unwrap();
-        if current_char != ' ' {
-            if current_char.is_digit(10) {
-                if answer.0 < 0 {
-                    let mut initial_number = String::from(current_char);
-                    if initial_number == "1" {
-                        initial_number.push(char_iterator.next().unwrap());
-                    }
-                    answer.0 = initial_number.parse::<i8>().unwrap();
-                } else if answer.1 < 0 {
-                    let hour = char_iterator
-                        .next()
-                        .unwrap()
-                        .to_string()
-                        .parse::<i16>()
-                        .unwrap();
-                    answer.1 = hour;
-                } else if answer.4 < 0 {
-                    let mut year_index = 0;
-                    let mut year_string = String::from(current_char);
-                    loop {
-                        if year_index == 3 {
-                            break;
-                        } else {
-                            year_string.push(char_iterator.next().unwrap());
-                        }
-                        year_index += 1;
-                    }
-                    answer.4 = year_string.parse::<i16>().unwrap();
-                }
-            } else if current_char == ':' {
-                loop {
-                    let next_sub_char = char_iterator.next().unwrap();
-                    if next_sub_char == ' ' {
-                        break;
-                    }
-
-                    if answer.2 < 0 {
-                        let minute_1 = next_sub_char;
-                        let minute_2 = char_iterator.next().unwrap();
-                        answer.2 = format!("{}{}", minute_1, minute_2).parse::<i16>().unwrap();
-                    } else if answer.3 < 0 {
-                        let second_1 = char_iterator.next().unwrap();
-                        let second_2 = char_iterator.next().unwrap();
-                        answer.3 = format!("{}{}", second_1, second_2).parse::<i16>().unwrap();
-                    }
-                }
-            }
-        } else if answer.0 > 0
-            && answer.0 > 0
-            && answer.1 > 0
-            && answer.2 > 0
-            && answer.3 > 0
-            && answer.4 > 0
-        {
-            break;
-        }
-    }
+/// The offset of the date's fields from UTC. The timezone offset comes from the zone data and already includes daylight savings time.
+pub fn total_offset(date: &FirstDate) -> UtcOffset {
+    date.timezone_offset.unwrap_or_default()
+}
 
-    answer
+/// The canonical point in time behind the date's fields.
+pub fn instant_of(date: &FirstDate) -> Instant {
+    let offset = total_offset(date);
+    let offset_millis = offset.whole_seconds() as i64 * MILLISECONDS_IN_SECOND as i64;
+    Instant::new(local_epoch_millis(date) - offset_millis, offset)
 }
 
-pub fn add_helper(current_value: i16, time_to_add: i16, unit_modulus: i16) -> AddEnum {
-    let new_value = current_value + time_to_add;
-    if (new_value / unit_modulus as i16) < 1 {
-        if unit_modulus == MONTHS_IN_YEAR {
-            return AddEnum::SameUnit(
-                new_value as u16,
-                Some(MONTHS[(new_value - 1) as usize].0.to_string()),
-            );
-        }
-        return AddEnum::SameUnit(new_value as u16, None);
+/// The number of milliseconds in a fixed-length unit, `None` for months and years.
+pub fn millis_in_unit(unit: TimeUnit) -> Option<i64> {
+    match unit {
+        TimeUnit::Millisecond => Some(1),
+        TimeUnit::Second => Some(MILLISECONDS_IN_SECOND as i64),
+        TimeUnit::Minute => Some(MILLISECONDS_IN_MINUTE),
+        TimeUnit::Hour => Some(MILLISECONDS_IN_HOUR),
+        TimeUnit::Day => Some(MILLISECONDS_IN_DAY),
+        TimeUnit::Month | TimeUnit::Year => None,
     }
+}
 
-    let current_unit_new_value = (new_value % unit_modulus) as u16;
-    let next_unit_new_value = (new_value / unit_modulus) as u16;
-    if unit_modulus == MONTHS_IN_YEAR {
-        return AddEnum::NextUnit(
-            current_unit_new_value,
-            next_unit_new_value,
-            Some(MONTHS[(current_unit_new_value - 1) as usize].0.to_string()),
-        );
-    }
-    AddEnum::NextUnit(current_unit_new_value, next_unit_new_value, None)
+/// The local milliseconds of the earliest supported moment, midnight on January 1st of `MIN_YEAR`.
+pub fn min_local_millis() -> i64 {
+    days_from_civil(MIN_YEAR, 1, 1) * MILLISECONDS_IN_DAY
 }
 
-/// current_month_index is expected to be 1-indexed.
-pub fn add_day_helper(
-    current_day: i16,
-    time_to_add: i16,
-    current_month_index: i8,
-    is_leap_year: bool,
-) -> AddEnum {
-    let new_value = current_day + time_to_add;
-    let current_month_modulus = if is_leap_year && current_month_index == 2 {
-        MONTHS[(current_month_index - 1) as usize].1 + 1
-    } else {
-        MONTHS[(current_month_index - 1) as usize].1
-    };
-    if (new_value / current_month_modulus as i16) < 1 {
-        return AddEnum::SameUnit(new_value as u16, None);
-    }
+/// The local milliseconds of the latest supported moment, the last millisecond of `MAX_YEAR`.
+pub fn max_local_millis() -> i64 {
+    days_from_civil(MAX_YEAR + 1, 1, 1) * MILLISECONDS_IN_DAY - 1
+}
 
-    let current_unit_new_value = (new_value % current_month_modulus) as u16;
-    let next_unit_new_value = (new_value / current_month_modulus) as u16;
-    return AddEnum::NextUnit(
-        current_unit_new_value,
-        next_unit_new_value,
-        Some(MONTHS[(current_unit_new_value - 1) as usize].0.to_string()),
-    );
+/// Days since Jan 1 1970 of the given (proleptic Gregorian) date. month and day are expected to be 1-indexed.
+pub fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    // shift the year so it starts in March, that way the leap day is the last day of the year
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
 }
 
-pub fn generate_first_date_from_millis<'b>(millis: isize) -> FirstDate {
-    // convert the milliseconds to seconds
-    let epoch_seconds = millis / MILLISECONDS_IN_SECOND;
+/// The (proleptic Gregorian) date of a number of days since Jan 1 1970, the inverse of `days_from_civil`. Returns (year, month, day), month and day are 1-indexed.
+pub fn civil_from_days(epoch_days: i64) -> (i64, u8, u8) {
+    // work in 400 year eras starting on March 1st 0000, that way the leap day is the last day of the year
+    let days = epoch_days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u8;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
 
-    // convert the seconds to days
-    let epoch_days = epoch_seconds / EPOCH_DAY;
+/// The number of days in a month (1-indexed) of the given year.
+pub fn days_in_month(year: i64, month: u8) -> u8 {
+    let days = MONTHS[(month - 1) as usize].1 as u8;
+    if month == 2 && is_leap_year(year) {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// Formats a year the ISO 8601 way: four digits for years 0 to 9999, otherwise a sign and at least four digits (for example "-0043" or "+10000").
+pub fn format_iso_year(year: i64) -> String {
+    if (0..=9999).contains(&year) {
+        format!("{:04}", year)
+    } else {
+        format!("{:+05}", year)
+    }
+}
 
-    let day_milliseconds = epoch_days * EPOCH_DAY * MILLISECONDS_IN_SECOND;
+/// Returns `FirstDateError::InvalidField` if the value is outside of the (inclusive) range.
+pub fn check_field(
+    field: &'static str,
+    value: i64,
+    min: i64,
+    max: i64,
+) -> Result<(), FirstDateError> {
+    if value < min || value > max {
+        return Err(FirstDateError::InvalidField { field, value });
+    }
+    Ok(())
+}
 
-    // convert the days to years. We need to account for leap years
-    let epoch_years = (epoch_days as f32) / DAYS_IN_YEAR_WITH_LEAP;
+/// Validates the calendar fields and returns the number of milliseconds since Jan 1 1970 they represent (ignoring timezones).
+pub fn millis_from_fields(
+    year: i64,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    millisecond: u16,
+) -> Result<i64, FirstDateError> {
+    check_field("year", year, MIN_YEAR, MAX_YEAR)?;
+    check_field("month", month as i64, 1, 12)?;
+    check_field("day", day as i64, 1, days_in_month(year, month) as i64)?;
+    check_field("hour", hour as i64, 0, 23)?;
+    check_field("minute", minute as i64, 0, 59)?;
+    check_field("second", second as i64, 0, 59)?;
+    check_field("millisecond", millisecond as i64, 0, 999)?;
+
+    Ok(days_from_civil(year, month, day) * MILLISECONDS_IN_DAY
+        + hour as i64 * MILLISECONDS_IN_HOUR
+        + minute as i64 * MILLISECONDS_IN_MINUTE
+        + second as i64 * MILLISECONDS_IN_SECOND as i64
+        + millisecond as i64)
+}
 
-    // use the years to calculate the current year
-    let year = (epoch_years + EPOCH_YEAR) as u16;
+pub fn generate_first_date_from_millis(millis: isize) -> FirstDate {
+    // stay within the supported years so the year fits in the field
+    let millis = (millis as i64).clamp(min_local_millis(), max_local_millis());
 
-    // at this point, the number of years is a fraction (something like 55.382385) and we want to get rid of the whole number and then multiply by the number of days in a year to get the number of days again.
-    let mut remaining_days = (epoch_years - epoch_years.floor()) * DAYS_IN_YEAR_WITH_LEAP;
+    // convert the milliseconds to days, flooring so instants before 1970 land on the previous day
+    let epoch_days = millis.div_euclid(MILLISECONDS_IN_DAY);
+    let (year, month, day) = civil_from_days(epoch_days);
+    let year = year as i32;
 
     // is the current year a leap year?
-    let is_current_year_leap_year = is_leap_year(year);
-
-    // calculate the current month from the remaining days
-    let mut month_index: i8 = 0;
-    let mut month: &str = "";
-    for (index, (current_month, days_in_month)) in MONTHS.iter().enumerate() {
-        if remaining_days <= *(days_in_month) as f32 {
-            month_index = index as i8;
-            month = current_month;
-            break;
-        } else if is_current_year_leap_year && current_month.to_owned() == "February" {
-            remaining_days -= *(days_in_month) as f32;
-            remaining_days -= 1.0;
-        } else {
-            remaining_days -= *(days_in_month) as f32;
-        }
-    }
-
-    // calculate the current day from the remaining days
-    let day = (remaining_days.floor() as u8) + 1; // add one because days are 1-based
+    let is_current_year_leap_year = is_leap_year(year as i64);
 
-    // calculate the current hour from the remaining days
-    let min_in_ms = 60 * MILLISECONDS_IN_SECOND;
-    let hour_in_ms = 60 * 60 * MILLISECONDS_IN_SECOND;
-    let remaining_milliseconds = millis - day_milliseconds;
-    let hour = (remaining_milliseconds / hour_in_ms) as u8;
+    // calculate the current hour from the milliseconds left in the day
+    let remaining_milliseconds = millis.rem_euclid(MILLISECONDS_IN_DAY);
+    let hour = (remaining_milliseconds / MILLISECONDS_IN_HOUR) as u8;
 
-    // calculate the current minute from the remaining days
-    let remaining_after_hours = remaining_milliseconds % hour_in_ms;
-    let minute = (remaining_after_hours / min_in_ms) as u8;
+    // calculate the current minute from the remaining after hours
+    let remaining_after_hours = remaining_milliseconds % MILLISECONDS_IN_HOUR;
+    let minute = (remaining_after_hours / MILLISECONDS_IN_MINUTE) as u8;
 
-    // calculate the current second from the remaining days
-    let remaining_after_minutes = remaining_after_hours % min_in_ms;
-    let second = (remaining_after_minutes / MILLISECONDS_IN_SECOND) as u8;
+    // calculate the current second from the remaining after minutes
+    let remaining_after_minutes = remaining_after_hours % MILLISECONDS_IN_MINUTE;
+    let second = (remaining_after_minutes / MILLISECONDS_IN_SECOND as i64) as u8;
 
-    // calculate the current millisecond from the remaining after minutes
-    let millisecond = (remaining_after_minutes % MILLISECONDS_IN_SECOND) as u16;
+    // calculate the current millisecond from the remaining after seconds
+    let millisecond = (remaining_after_minutes % MILLISECONDS_IN_SECOND as i64) as u16;
 
-    return FirstDate {
+    FirstDate {
         day,
         hour,
         year,
@@ -351,60 +298,44 @@ pub fn generate_first_date_from_millis<'b>(millis: isize) -> FirstDate {
         millisecond,
         timezone_offset: None,
         is_daylight_savings: None,
-        month: (month_index + 1) as u8, // for loops start at 0 so we need to add 1.
-        month_name: String::from(month),
+        month,
+        month_name: String::from(MONTHS[(month - 1) as usize].0),
         timezone_name: String::from("unset"),
         is_leap_year: is_current_year_leap_year,
-    };
+    }
 }
 
 pub fn epoch_milliseconds() -> isize {
     let start = SystemTime::now();
-    let duration = start
-        .duration_since(UNIX_EPOCH)
-        .expect("Time went backwards?");
-    return duration.as_millis() as isize;
+    match start.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_millis() as isize,
+        // the system clock is set before 1970
+        Err(error) => -(error.duration().as_millis() as isize),
+    }
 }
 
-pub fn is_leap_year(year_to_test: u16) -> bool {
+pub fn is_leap_year(year_to_test: i64) -> bool {
     year_to_test % 4 == 0 && (year_to_test % 100 != 0 || year_to_test % 400 == 0)
 }
 
-pub fn local_timezone() -> String {
-    let raw_system_timezone_result = fs::read_to_string("/etc/timezone");
-    match raw_system_timezone_result {
-        Ok(system_timezone) => String::from(system_timezone.trim()),
-        Err(_) => String::from("Unable to get system timezone."),
-    }
-}
-
-pub fn local_timezone_offset(system_timezone: String) -> Result<i16, Error> {
-    let timezone_table = File::open("resources/timezone_list.txt")?;
-    let reader = BufReader::new(timezone_table);
-
-    for line in reader.lines() {
-        let current_line = match line {
-            Ok(data) => data,
-            Err(_) => String::from(""),
-        };
-
-        if current_line.contains(&system_timezone) {
-            let matched_line: Vec<&str> = current_line.split(" ").collect();
-            let result = matched_line[1][..matched_line.len()].parse::<i16>();
-            return match result {
-                Ok(timezone) => Ok(timezone),
-                Err(_e) => Err(Error::new(
-                    ErrorKind::Other,
-                    format!("Could not parse the timezone value: {}", &current_line),
-                )),
-            };
+/// Reads the system timezone name from `/etc/timezone`, falling back to where the `/etc/localtime` link points.
+pub fn local_timezone() -> Result<String, FirstDateError> {
+    if let Ok(system_timezone) = fs::read_to_string("/etc/timezone") {
+        if !system_timezone.trim().is_empty() {
+            return Ok(String::from(system_timezone.trim()));
         }
     }
 
-    Err(Error::new(
-        ErrorKind::NotFound,
-        "Could not find the provided timezone",
-    ))
+    let link = fs::read_link("/etc/localtime")
+        .map_err(|error| FirstDateError::MissingTimezoneData(error.to_string()))?;
+    let link = link.to_string_lossy();
+    match link.split_once("zoneinfo/") {
+        Some((_, timezone_name)) => Ok(String::from(timezone_name)),
+        None => Err(FirstDateError::MissingTimezoneData(format!(
+            "Unable to get the system timezone from {}",
+            link
+        ))),
+    }
 }
 
 #[cfg(test)]
@@ -413,17 +344,17 @@ mod tests {
 
     #[test]
     fn is_leap_year_test_true_century() {
-        assert_eq!(is_leap_year(2000), true);
+        assert!(is_leap_year(2000));
     }
 
     #[test]
     fn is_leap_year_test_true_mod4() {
-        assert_eq!(is_leap_year(2020), true);
+        assert!(is_leap_year(2020));
     }
 
     #[test]
     fn is_leap_year_test_false() {
-        assert_eq!(is_leap_year(2021), false);
+        assert!(!is_leap_year(2021));
     }
 
     #[test]
@@ -438,6 +369,174 @@ mod tests {
         assert_eq!(result.minute, 46);
         assert_eq!(result.second, 48);
         assert_eq!(result.millisecond, 447);
-        assert_eq!(result.is_leap_year, false);
+        assert!(!result.is_leap_year);
+    }
+
+    #[test]
+    fn generate_first_date_from_millis_test_bc_positive() {
+        // 0001-01-01T00:00:00Z minus one millisecond is the last moment of 1 BC (year 0)
+        let result = generate_first_date_from_millis(-62_135_596_800_001);
+        assert_eq!((result.year, result.month, result.day), (0, 12, 31));
+        assert!(result.is_leap_year);
+
+        // 44 BC (year -43), March 15th
+        let ides = days_from_civil(-43, 3, 15) * MILLISECONDS_IN_DAY;
+        let result = generate_first_date_from_millis(ides as isize);
+        assert_eq!((result.year, result.month, result.day), (-43, 3, 15));
+        assert_eq!(result.month_name, "March");
+
+        // before the earliest supported year
+        let result = generate_first_date_from_millis(-100_000_000_000_000_000);
+        assert_eq!(
+            (result.year as i64, result.month, result.day),
+            (MIN_YEAR, 1, 1)
+        );
+        assert_eq!((result.hour, result.minute, result.millisecond), (0, 0, 0));
+    }
+
+    #[test]
+    fn format_iso_year_positive() {
+        assert_eq!(format_iso_year(2025), "2025");
+        assert_eq!(format_iso_year(43), "0043");
+        assert_eq!(format_iso_year(-43), "-0043");
+        assert_eq!(format_iso_year(12345), "+12345");
+    }
+
+    #[test]
+    fn era_of_positive() {
+        assert_eq!(era_of(2025), (Era::Ad, 2025));
+        assert_eq!(era_of(1), (Era::Ad, 1));
+        assert_eq!(era_of(0), (Era::Bc, 1));
+        assert_eq!(era_of(-43), (Era::Bc, 44));
+    }
+
+    #[test]
+    fn generate_first_date_from_millis_test_negative_millis() {
+        // 1969-12-31T23:59:59.999Z
+        let result = generate_first_date_from_millis(-1);
+        assert_eq!(result.year, 1969);
+        assert_eq!(result.month, 12);
+        assert_eq!(result.day, 31);
+        assert_eq!(result.hour, 23);
+        assert_eq!(result.minute, 59);
+        assert_eq!(result.second, 59);
+        assert_eq!(result.millisecond, 999);
+
+        // 1900-03-01T00:00:00Z, 1900 was not a leap year
+        let result = generate_first_date_from_millis(-2203891200000);
+        assert_eq!((result.year, result.month, result.day), (1900, 3, 1));
+        assert!(!result.is_leap_year);
+    }
+
+    #[test]
+    fn generate_first_date_from_millis_test_year_boundaries() {
+        // 2024-12-31T23:59:59.999Z and 2025-01-01T00:00:00Z
+        let result = generate_first_date_from_millis(1735689599999);
+        assert_eq!((result.year, result.month, result.day), (2024, 12, 31));
+        assert!(result.is_leap_year);
+        let result = generate_first_date_from_millis(1735689600000);
+        assert_eq!((result.year, result.month, result.day), (2025, 1, 1));
+        assert_eq!(result.month_name, "January");
+
+        // 2400-02-29T12:00:00Z
+        let result = generate_first_date_from_millis(13574606400000);
+        assert_eq!((result.year, result.month, result.day), (2400, 2, 29));
+    }
+
+    #[test]
+    fn civil_from_days_test_epoch() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(days_from_civil(0, 3, 1), -719468);
+    }
+
+    #[test]
+    fn civil_from_days_test_exhaustive() {
+        // walk every day from year -10000 to 10000 and check it against a simple calendar counter
+        let mut expected = (-10000, 1, 1);
+        let first_day = days_from_civil(-10000, 1, 1);
+        let last_day = days_from_civil(10000, 12, 31);
+        for epoch_days in first_day..=last_day {
+            assert_eq!(civil_from_days(epoch_days), expected);
+            assert_eq!(
+                days_from_civil(expected.0, expected.1, expected.2),
+                epoch_days
+            );
+
+            let (year, month, day) = expected;
+            expected = if day < days_in_month(year, month) {
+                (year, month, day + 1)
+            } else if month < 12 {
+                (year, month + 1, 1)
+            } else {
+                (year + 1, 1, 1)
+            };
+        }
+        assert_eq!(expected, (10001, 1, 1));
+    }
+
+    #[test]
+    fn days_in_month_test_february() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2025, 4), 30);
+    }
+
+    #[test]
+    fn millis_from_fields_test_negative() {
+        assert_eq!(
+            millis_from_fields(2025, 2, 29, 0, 0, 0, 0),
+            Err(FirstDateError::InvalidField {
+                field: "day",
+                value: 29
+            })
+        );
+        assert_eq!(
+            millis_from_fields(2025, 13, 1, 0, 0, 0, 0),
+            Err(FirstDateError::InvalidField {
+                field: "month",
+                value: 13
+            })
+        );
+    }
+
+    #[test]
+    fn timezone_info_test_positive() {
+        // 2025-01-15T12:00:00Z and 2025-07-15T12:00:00Z
+        let (january, july) = (1736942400, 1752580800);
+        let offset_at = |timezone_name: &str, epoch_seconds: i64| {
+            let zone = timezone_info(timezone_name).unwrap();
+            zone.utc_offset_at(epoch_seconds)
+        };
+        assert_eq!(offset_at("America/Chicago", january), -6 * 3600);
+        assert_eq!(offset_at("America/Chicago", july), -5 * 3600);
+        assert_eq!(offset_at("Asia/Kathmandu", july), 5 * 3600 + 45 * 60);
+
+        // daylight savings time on Lord Howe Island is only half an hour
+        assert_eq!(offset_at("Australia/Lord_Howe", january), 11 * 3600);
+        assert_eq!(offset_at("Australia/Lord_Howe", july), 10 * 3600 + 30 * 60);
+    }
+
+    #[test]
+    fn timezone_info_test_negative() {
+        let result = timezone_info("Chicago");
+        assert_eq!(
+            result.unwrap_err(),
+            FirstDateError::UnknownTimezone(String::from("Chicago"))
+        );
+    }
+
+    #[test]
+    fn local_time_type_at_wall_clock_positive() {
+        let zone = timezone_info("America/Chicago").unwrap();
+        let wall_clock = |month, day, hour| {
+            let millis = millis_from_fields(2025, month, day, hour, 0, 0, 0).unwrap();
+            local_time_type_at_wall_clock(&zone, millis)
+        };
+        assert_eq!(wall_clock(1, 15, 12).abbreviation, "CST");
+        assert_eq!(wall_clock(7, 15, 12).abbreviation, "CDT");
+        // the day before and after the switch to daylight savings time on March 9th
+        assert_eq!(wall_clock(3, 9, 1).utc_offset, -6 * 3600);
+        assert_eq!(wall_clock(3, 9, 4).utc_offset, -5 * 3600);
     }
 }
@@ -0,0 +1,175 @@
+use crate::date_utilities::{
+    millis_in_unit, TimeUnit, MILLISECONDS_IN_DAY, MILLISECONDS_IN_HOUR, MILLISECONDS_IN_MINUTE,
+};
+use std::ops::{Add, Neg, Sub};
+
+const MILLISECONDS_IN_SECOND: i64 = 1000;
+const MILLISECONDS_IN_WEEK: i64 = 7 * MILLISECONDS_IN_DAY;
+
+/// A signed, fixed amount of time with millisecond precision, for example the time between two `FirstDate`s.
+/// Constructors and the `+`, `-` and negation operators saturate instead of overflowing, use `checked_add` and `checked_sub` to detect it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Duration {
+    millis: i64,
+}
+
+impl Duration {
+    pub const ZERO: Duration = Duration { millis: 0 };
+    pub const MIN: Duration = Duration { millis: i64::MIN };
+    pub const MAX: Duration = Duration { millis: i64::MAX };
+
+    pub fn milliseconds(milliseconds: i64) -> Duration {
+        Duration {
+            millis: milliseconds,
+        }
+    }
+
+    pub fn seconds(seconds: i64) -> Duration {
+        Duration::milliseconds(seconds.saturating_mul(MILLISECONDS_IN_SECOND))
+    }
+
+    pub fn minutes(minutes: i64) -> Duration {
+        Duration::milliseconds(minutes.saturating_mul(MILLISECONDS_IN_MINUTE))
+    }
+
+    pub fn hours(hours: i64) -> Duration {
+        Duration::milliseconds(hours.saturating_mul(MILLISECONDS_IN_HOUR))
+    }
+
+    /// A day is always 24 hours long.
+    pub fn days(days: i64) -> Duration {
+        Duration::milliseconds(days.saturating_mul(MILLISECONDS_IN_DAY))
+    }
+
+    pub fn weeks(weeks: i64) -> Duration {
+        Duration::milliseconds(weeks.saturating_mul(MILLISECONDS_IN_WEEK))
+    }
+
+    /// Creates a duration from an amount of a `TimeUnit`. Returns `None` for `TimeUnit::Month` and `TimeUnit::Year` because their length depends on the calendar (use `add` on the date instead).
+    pub fn of(amount: i64, unit: TimeUnit) -> Option<Duration> {
+        let unit_millis = millis_in_unit(unit)?;
+        Some(Duration::milliseconds(amount.saturating_mul(unit_millis)))
+    }
+
+    pub fn whole_milliseconds(&self) -> i64 {
+        self.millis
+    }
+
+    /// The number of whole seconds, truncated toward zero.
+    pub fn whole_seconds(&self) -> i64 {
+        self.millis / MILLISECONDS_IN_SECOND
+    }
+
+    /// The number of whole minutes, truncated toward zero.
+    pub fn whole_minutes(&self) -> i64 {
+        self.millis / MILLISECONDS_IN_MINUTE
+    }
+
+    /// The number of whole hours, truncated toward zero.
+    pub fn whole_hours(&self) -> i64 {
+        self.millis / MILLISECONDS_IN_HOUR
+    }
+
+    /// The number of whole days, truncated toward zero. For example 47 hours is 1 day.
+    pub fn whole_days(&self) -> i64 {
+        self.millis / MILLISECONDS_IN_DAY
+    }
+
+    /// The number of whole weeks, truncated toward zero.
+    pub fn whole_weeks(&self) -> i64 {
+        self.millis / MILLISECONDS_IN_WEEK
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.millis == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.millis < 0
+    }
+
+    pub fn abs(&self) -> Duration {
+        Duration::milliseconds(self.millis.saturating_abs())
+    }
+
+    pub fn checked_add(&self, other: Duration) -> Option<Duration> {
+        Some(Duration::milliseconds(
+            self.millis.checked_add(other.millis)?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: Duration) -> Option<Duration> {
+        Some(Duration::milliseconds(
+            self.millis.checked_sub(other.millis)?,
+        ))
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        Duration::milliseconds(self.millis.saturating_add(other.millis))
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        Duration::milliseconds(self.millis.saturating_sub(other.millis))
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        Duration::milliseconds(self.millis.saturating_neg())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors_positive() {
+        assert_eq!(Duration::seconds(90).whole_minutes(), 1);
+        assert_eq!(Duration::hours(47).whole_days(), 1);
+        assert_eq!(Duration::weeks(2).whole_days(), 14);
+        assert_eq!(Duration::days(-3).whole_hours(), -72);
+        assert_eq!(
+            Duration::of(15, TimeUnit::Minute),
+            Some(Duration::seconds(900))
+        );
+        assert_eq!(Duration::of(1, TimeUnit::Month), None);
+    }
+
+    #[test]
+    fn constructors_saturate() {
+        assert_eq!(Duration::days(i64::MAX).whole_milliseconds(), i64::MAX);
+        assert_eq!(Duration::days(i64::MIN).whole_milliseconds(), i64::MIN);
+    }
+
+    #[test]
+    fn arithmetic_positive() {
+        let sum = Duration::hours(1) + Duration::minutes(30);
+        assert_eq!(sum.whole_minutes(), 90);
+        assert_eq!((sum - Duration::hours(2)).whole_minutes(), -30);
+        assert!((-sum).is_negative());
+        assert_eq!((-sum).abs(), sum);
+        assert_eq!(Duration::milliseconds(i64::MAX).checked_add(sum), None);
+    }
+
+    #[test]
+    fn arithmetic_saturate() {
+        let one = Duration::milliseconds(1);
+        assert_eq!(Duration::MAX + one, Duration::MAX);
+        assert_eq!(Duration::MIN - one, Duration::MIN);
+        assert_eq!(Duration::MIN + Duration::MIN, Duration::MIN);
+        assert_eq!(Duration::MAX - Duration::MIN, Duration::MAX);
+        assert_eq!(-Duration::MIN, Duration::MAX);
+        assert_eq!(-Duration::MAX, Duration::MIN + one);
+    }
+}
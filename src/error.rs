@@ -0,0 +1,152 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reasons an operation on a `FirstDate` can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FirstDateError {
+    /// The timezone name was not found, for example "America/Chicgo".
+    UnknownTimezone(String),
+    /// The timezone data (the system timezone or the TZif files) could not be read.
+    MissingTimezoneData(String),
+    /// A field is outside of its valid range, for example month 13 or minute 60.
+    InvalidField { field: &'static str, value: i64 },
+    /// A string could not be parsed.
+    Parse(String),
+    /// The result is outside of the range of dates that can be represented.
+    Overflow,
+}
+
+impl fmt::Display for FirstDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FirstDateError::UnknownTimezone(timezone_name) => {
+                write!(f, "Could not find the timezone: {}", timezone_name)
+            }
+            FirstDateError::MissingTimezoneData(reason) => {
+                write!(f, "Could not read the timezone data: {}", reason)
+            }
+            FirstDateError::InvalidField { field, value } => {
+                write!(f, "Invalid value for {}: {}", field, value)
+            }
+            FirstDateError::Parse(reason) => write!(f, "Could not parse the value: {}", reason),
+            FirstDateError::Overflow => write!(f, "The date is out of the supported range"),
+        }
+    }
+}
+
+impl Error for FirstDateError {}
+
+/// Why a string could not be parsed, see `ParseError`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input ended before the value was complete.
+    UnexpectedEnd { expected: &'static str },
+    /// A character that does not fit the format, for example a letter where a digit was expected.
+    UnexpectedCharacter { found: char, expected: &'static str },
+    /// The value was complete but there are characters left.
+    TrailingCharacters,
+    /// A field was read but is outside of its valid range, for example month 13 or February 30th.
+    InvalidField { field: &'static str, value: i64 },
+    /// A timezone name without a TZif file, for example "America/Chicgo".
+    UnknownTimezone(String),
+}
+
+/// The error of the parsers, with the byte position in the input where the problem was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub position: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub fn new(position: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { position, kind }
+    }
+
+    pub fn invalid_field(position: usize, field: &'static str, value: i64) -> ParseError {
+        ParseError::new(position, ParseErrorKind::InvalidField { field, value })
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedEnd { expected } => {
+                write!(
+                    f,
+                    "Unexpected end at byte {}, expected {}",
+                    self.position, expected
+                )
+            }
+            ParseErrorKind::UnexpectedCharacter { found, expected } => write!(
+                f,
+                "Unexpected character '{}' at byte {}, expected {}",
+                found, self.position, expected
+            ),
+            ParseErrorKind::TrailingCharacters => {
+                write!(
+                    f,
+                    "Unexpected characters after the value at byte {}",
+                    self.position
+                )
+            }
+            ParseErrorKind::InvalidField { field, value } => write!(
+                f,
+                "Invalid value for {} at byte {}: {}",
+                field, self.position, value
+            ),
+            ParseErrorKind::UnknownTimezone(timezone_name) => write!(
+                f,
+                "Unknown timezone at byte {}: {}",
+                self.position, timezone_name
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for FirstDateError {
+    fn from(error: ParseError) -> FirstDateError {
+        FirstDateError::Parse(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_positive() {
+        let error = FirstDateError::InvalidField {
+            field: "month",
+            value: 13,
+        };
+        assert_eq!(error.to_string(), "Invalid value for month: 13");
+        assert_eq!(
+            FirstDateError::UnknownTimezone(String::from("Mars/Olympus_Mons")).to_string(),
+            "Could not find the timezone: Mars/Olympus_Mons"
+        );
+    }
+
+    #[test]
+    fn parse_error_display_positive() {
+        let error = ParseError::new(
+            5,
+            ParseErrorKind::UnexpectedCharacter {
+                found: 'x',
+                expected: "month",
+            },
+        );
+        assert_eq!(
+            error.to_string(),
+            "Unexpected character 'x' at byte 5, expected month"
+        );
+        assert_eq!(
+            FirstDateError::from(error),
+            FirstDateError::Parse(String::from(
+                "Unexpected character 'x' at byte 5, expected month"
+            ))
+        );
+    }
+}
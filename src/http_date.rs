@@ -0,0 +1,267 @@
+use crate::date_utilities::{check_field, epoch_milliseconds, MONTHS};
+use crate::error::{FirstDateError, ParseError};
+use crate::iso8601::with_time;
+use crate::rfc2822::scan_month_abbreviation;
+use crate::scanner::Scanner;
+use crate::{FirstDate, FirstDateOperations, UtcOffset, Weekday};
+
+impl FirstDate {
+    /// Formats the date for an HTTP header such as `Date` or `Last-Modified`, in the IMF-fixdate format of RFC 9110, for example "Fri, 23 May 2025 03:46:48 GMT". The date is always written in GMT whatever its timezone, and the milliseconds are dropped.
+    /// Returns `FirstDateError::InvalidField` for the year if it is before 0 or after 9999 in GMT, the format only has four digit years.
+    pub fn to_http_date(&self) -> Result<String, FirstDateError> {
+        let utc = FirstDate::from_instant(self.instant().with_offset(UtcOffset::UTC));
+        check_field("year", utc.year as i64, 0, 9999)?;
+        Ok(format!(
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            utc.weekday().abbreviation(),
+            utc.day,
+            &MONTHS[utc.month as usize - 1].0[..3],
+            utc.year,
+            utc.hour,
+            utc.minute,
+            utc.second
+        ))
+    }
+
+    /// Parses an HTTP date in any of the three formats of RFC 9110 and returns it in UTC:
+    /// - IMF-fixdate, "Fri, 23 May 2025 03:46:48 GMT"
+    /// - the obsolete RFC 850 format, "Friday, 23-May-25 03:46:48 GMT"
+    /// - the obsolete asctime format, "Fri May 23 03:46:48 2025"
+    ///
+    /// The two digit years of the RFC 850 format are in the 50 years before or after the current year (in UTC). The weekday must match the date.
+    pub fn parse_http_date(value: &str) -> Result<FirstDate, ParseError> {
+        let now = FirstDate::from_millis(epoch_milliseconds());
+        FirstDate::parse_http_date_with_reference(value, &now)
+    }
+
+    /// Same as `parse_http_date` but the two digit years of the RFC 850 format are in the 50 years before or after the year of `reference` (in UTC) instead of the current year.
+    pub fn parse_http_date_with_reference(
+        value: &str,
+        reference: &FirstDate,
+    ) -> Result<FirstDate, ParseError> {
+        let reference_year =
+            FirstDate::from_instant(reference.instant().with_offset(UtcOffset::UTC)).year;
+        let mut scanner = Scanner::new(value);
+
+        // the full names first, "Fri" is also the beginning of "Friday"
+        let (weekday, is_full_name) = Weekday::ALL
+            .into_iter()
+            .find(|weekday| scanner.eat_ignore_case(weekday.name()))
+            .map(|weekday| (weekday, true))
+            .or_else(|| {
+                Weekday::ALL
+                    .into_iter()
+                    .find(|weekday| scanner.eat_ignore_case(weekday.abbreviation()))
+                    .map(|weekday| (weekday, false))
+            })
+            .ok_or_else(|| scanner.unexpected("weekday"))?;
+
+        let (year, month, day, day_start, time) = if is_full_name {
+            // RFC 850
+            scanner.expect(',', "','")?;
+            scanner.expect(' ', "' '")?;
+            let day_start = scanner.position();
+            let day = scanner.field(2, "day", 1, 31)?;
+            scanner.expect('-', "'-'")?;
+            let month = scan_month_abbreviation(&mut scanner)?;
+            scanner.expect('-', "'-'")?;
+            let year = scanner.field(2, "year", 0, 99)?;
+            let year = two_digit_year(year, reference_year as i64);
+            scanner.expect(' ', "' '")?;
+            let time = scan_time(&mut scanner)?;
+            scanner.expect(' ', "' '")?;
+            expect_gmt(&mut scanner)?;
+            (year, month, day, day_start, time)
+        } else if scanner.eat(',') {
+            // IMF-fixdate
+            scanner.expect(' ', "' '")?;
+            let day_start = scanner.position();
+            let day = scanner.field(2, "day", 1, 31)?;
+            scanner.expect(' ', "' '")?;
+            let month = scan_month_abbreviation(&mut scanner)?;
+            scanner.expect(' ', "' '")?;
+            let year = scanner.field(4, "year", 0, 9999)?;
+            scanner.expect(' ', "' '")?;
+            let time = scan_time(&mut scanner)?;
+            scanner.expect(' ', "' '")?;
+            expect_gmt(&mut scanner)?;
+            (year, month, day, day_start, time)
+        } else {
+            // asctime, the day is padded with a space
+            scanner.expect(' ', "' '")?;
+            let month = scan_month_abbreviation(&mut scanner)?;
+            scanner.expect(' ', "' '")?;
+            scanner.eat(' ');
+            let day_start = scanner.position();
+            let day = scanner.field_between(1, 2, "day", 1, 31)?;
+            scanner.expect(' ', "' '")?;
+            let time = scan_time(&mut scanner)?;
+            scanner.expect(' ', "' '")?;
+            let year = scanner.field(4, "year", 0, 9999)?;
+            (year, month, day, day_start, time)
+        };
+        scanner.finish()?;
+
+        let date = FirstDate::from_ymd(year as i32, month, day as u8)
+            .map_err(|_| ParseError::invalid_field(day_start, "day", day))?;
+        if date.weekday() != weekday {
+            let number = weekday.number_from_monday() as i64;
+            return Err(ParseError::invalid_field(0, "weekday", number));
+        }
+        Ok(with_time(&date, time, UtcOffset::UTC))
+    }
+}
+
+/// Reads "hh:mm:ss". A leap second is read as the 59th second.
+fn scan_time(scanner: &mut Scanner) -> Result<(u8, u8, u8, u16), ParseError> {
+    let hour = scanner.field(2, "hour", 0, 23)?;
+    scanner.expect(':', "':'")?;
+    let minute = scanner.field(2, "minute", 0, 59)?;
+    scanner.expect(':', "':'")?;
+    let second = scanner.field(2, "second", 0, 60)?.min(59);
+    Ok((hour as u8, minute as u8, second as u8, 0))
+}
+
+fn expect_gmt(scanner: &mut Scanner) -> Result<(), ParseError> {
+    if scanner.eat_ignore_case("GMT") {
+        return Ok(());
+    }
+    Err(scanner.unexpected("'GMT'"))
+}
+
+/// RFC 9110 reads a two digit year that would be more than 50 years in the future as the most recent year in the past with the same last two digits.
+fn two_digit_year(year: i64, current_year: i64) -> i64 {
+    let century = current_year.div_euclid(100) * 100;
+    let mut full_year = century + year;
+    if full_year > current_year + 50 {
+        full_year -= 100;
+    } else if full_year <= current_year - 50 {
+        full_year += 100;
+    }
+    full_year
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+
+    #[test]
+    fn to_http_date_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(sut.to_http_date().unwrap(), "Fri, 23 May 2025 03:46:48 GMT");
+
+        sut.set_timezone(String::from("America/Chicago"));
+        assert_eq!(sut.hour, 22);
+        assert_eq!(sut.to_http_date().unwrap(), "Fri, 23 May 2025 03:46:48 GMT");
+
+        let sut = FirstDate::from_ymd_hms(1994, 11, 6, 8, 49, 37).unwrap();
+        assert_eq!(sut.to_http_date().unwrap(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn to_http_date_negative() {
+        let sut = FirstDate::from_ymd(-43, 3, 15).unwrap();
+        assert_eq!(
+            sut.to_http_date(),
+            Err(FirstDateError::InvalidField {
+                field: "year",
+                value: -43
+            })
+        );
+
+        // the year is checked in GMT, December 31st 9999 in Los Angeles is already 10000 in GMT
+        let mut sut = FirstDate::from_ymd_hms(10000, 1, 1, 2, 0, 0).unwrap();
+        sut.set_timezone(String::from("America/Los_Angeles"));
+        assert_eq!(sut.year, 9999);
+        assert_eq!(
+            sut.to_http_date(),
+            Err(FirstDateError::InvalidField {
+                field: "year",
+                value: 10000
+            })
+        );
+    }
+
+    #[test]
+    fn parse_http_date_positive() {
+        let expected = FirstDate::from_ymd_hms(1994, 11, 6, 8, 49, 37).unwrap();
+        let reference = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        for value in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+            "Sun Nov 06 08:49:37 1994",
+            "sun, 06 nov 1994 08:49:37 gmt",
+        ] {
+            assert_eq!(
+                FirstDate::parse_http_date_with_reference(value, &reference),
+                Ok(expected.clone()),
+                "{}",
+                value
+            );
+        }
+
+        let sut = FirstDate::parse_http_date("Fri, 23 May 2025 03:46:48 GMT").unwrap();
+        assert!(sut.timezone_offset.unwrap().is_utc());
+        assert_eq!(sut.to_http_date().unwrap(), "Fri, 23 May 2025 03:46:48 GMT");
+    }
+
+    #[test]
+    fn parse_http_date_with_reference_positive() {
+        let reference = FirstDate::from_ymd(2030, 1, 1).unwrap();
+        let value = "Friday, 06-Nov-76 08:49:37 GMT";
+        let sut = FirstDate::parse_http_date_with_reference(value, &reference).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (2076, 11, 6));
+
+        // the year of the reference is taken in UTC, 2095 is more than 50 years after 2044
+        let mut reference = FirstDate::from_ymd_hms(2044, 12, 31, 20, 0, 0).unwrap();
+        reference.set_timezone(String::from("Asia/Tokyo"));
+        assert_eq!(reference.year, 2045);
+        let value = "Monday, 06-Nov-95 08:49:37 GMT";
+        let sut = FirstDate::parse_http_date_with_reference(value, &reference).unwrap();
+        assert_eq!(sut.year, 1995);
+    }
+
+    #[test]
+    fn two_digit_year_positive() {
+        assert_eq!(two_digit_year(94, 2025), 1994);
+        assert_eq!(two_digit_year(25, 2025), 2025);
+        assert_eq!(two_digit_year(75, 2025), 2075);
+        assert_eq!(two_digit_year(76, 2025), 1976);
+        assert_eq!(two_digit_year(10, 2080), 2110);
+        assert_eq!(two_digit_year(30, 2080), 2130);
+        assert_eq!(two_digit_year(31, 2080), 2031);
+    }
+
+    #[test]
+    fn parse_http_date_negative() {
+        assert_eq!(
+            FirstDate::parse_http_date("Mon, 06 Nov 1994 08:49:37 GMT"),
+            Err(ParseError::invalid_field(0, "weekday", 1))
+        );
+        assert_eq!(
+            FirstDate::parse_http_date("Sun, 06 Nov 1994 08:49:37 EST"),
+            Err(ParseError::new(
+                26,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'E',
+                    expected: "'GMT'"
+                }
+            ))
+        );
+        for value in [
+            "",
+            "Sun, 06 Nov 1994 08:49:37 +0000",
+            "Sun, 6 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 08:49 GMT",
+            "Sun, 31 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-1994 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994 GMT",
+            "Sun, 06 Nov 1994 08:49:37 GMT ",
+        ] {
+            assert!(FirstDate::parse_http_date(value).is_err(), "{}", value);
+        }
+    }
+}
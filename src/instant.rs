@@ -0,0 +1,110 @@
+use crate::utc_offset::UtcOffset;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+const MILLISECONDS_IN_SECOND: i64 = 1000;
+
+/// A single point in time: signed milliseconds since Jan 1 1970 UTC, plus the UTC offset used to display it.
+/// Two instants are equal (and ordered) by the point in time alone, the offset does not take part in comparisons.
+#[derive(Debug, Clone, Copy)]
+pub struct Instant {
+    millis: i64,
+    offset: UtcOffset,
+}
+
+impl Instant {
+    pub fn new(millis: i64, offset: UtcOffset) -> Instant {
+        Instant { millis, offset }
+    }
+
+    /// Creates an instant displayed in UTC.
+    pub fn from_millis(millis: i64) -> Instant {
+        Instant::new(millis, UtcOffset::UTC)
+    }
+
+    /// Milliseconds since Jan 1 1970 UTC.
+    pub fn millis(&self) -> i64 {
+        self.millis
+    }
+
+    pub fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Milliseconds since Jan 1 1970 as seen on a wall clock at the offset. This is what the broken-down fields of a `FirstDate` are derived from.
+    pub fn local_millis(&self) -> i64 {
+        self.millis + self.offset.whole_seconds() as i64 * MILLISECONDS_IN_SECOND
+    }
+
+    /// The same point in time displayed at another offset.
+    pub fn with_offset(&self, offset: UtcOffset) -> Instant {
+        Instant::new(self.millis, offset)
+    }
+
+    /// Adds (or subtracts, if negative) milliseconds. Returns `None` on overflow.
+    pub fn checked_add_millis(&self, millis: i64) -> Option<Instant> {
+        Some(Instant::new(self.millis.checked_add(millis)?, self.offset))
+    }
+}
+
+impl PartialEq for Instant {
+    fn eq(&self, other: &Instant) -> bool {
+        self.millis == other.millis
+    }
+}
+
+impl Eq for Instant {}
+
+impl PartialOrd for Instant {
+    fn partial_cmp(&self, other: &Instant) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Instant {
+    fn cmp(&self, other: &Instant) -> Ordering {
+        self.millis.cmp(&other.millis)
+    }
+}
+
+impl Hash for Instant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.millis.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_millis_positive() {
+        let offset = UtcOffset::from_hms(5, 30, 0).unwrap();
+        let sut = Instant::new(1747972008447, offset);
+        assert_eq!(sut.local_millis(), 1747972008447 + 19_800_000);
+        assert_eq!(
+            sut.with_offset(UtcOffset::UTC).local_millis(),
+            1747972008447
+        );
+    }
+
+    #[test]
+    fn comparison_ignores_offset() {
+        let utc = Instant::from_millis(1000);
+        let chicago = Instant::new(1000, UtcOffset::from_hms(-6, 0, 0).unwrap());
+        assert_eq!(utc, chicago);
+        assert!(Instant::from_millis(999) < chicago);
+    }
+
+    #[test]
+    fn checked_add_millis_overflow() {
+        assert_eq!(Instant::from_millis(i64::MAX).checked_add_millis(1), None);
+        assert_eq!(
+            Instant::from_millis(-5)
+                .checked_add_millis(10)
+                .unwrap()
+                .millis(),
+            5
+        );
+    }
+}
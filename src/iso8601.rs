@@ -0,0 +1,489 @@
+use crate::date_utilities::{
+    days_from_civil, days_in_month, format_iso_year, total_offset, MAX_YEAR, MILLISECONDS_IN_DAY,
+    MILLISECONDS_IN_HOUR, MILLISECONDS_IN_MINUTE, MIN_YEAR,
+};
+use crate::error::ParseError;
+use crate::scanner::Scanner;
+use crate::{FirstDate, FirstDateOperations, Instant, UtcOffset, Weekday};
+
+/// The two ISO 8601 representations: basic leaves out the separators ("20250523T034648Z"), extended keeps them ("2025-05-23T03:46:48Z").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IsoFormat {
+    Basic,
+    #[default]
+    Extended,
+}
+
+/// How a zero offset from UTC is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UtcDesignator {
+    /// "Z", for example "2025-05-23T03:46:48Z".
+    #[default]
+    Z,
+    /// A numeric offset, for example "2025-05-23T03:46:48+00:00".
+    Offset,
+}
+
+/// The options of `FirstDate::to_iso8601`. The default is the extended format with milliseconds and "Z" for UTC, the same as `to_rfc3339`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Iso8601Options {
+    pub format: IsoFormat,
+    /// The number of digits after the decimal point of the seconds, from 0 (no fraction) to 9. Digits past the milliseconds are always 0.
+    pub fraction_digits: u8,
+    pub utc_designator: UtcDesignator,
+}
+
+impl Iso8601Options {
+    /// The RFC 3339 profile of ISO 8601, for example "2025-05-23T03:46:48.447Z".
+    pub const RFC3339: Iso8601Options = Iso8601Options {
+        format: IsoFormat::Extended,
+        fraction_digits: 3,
+        utc_designator: UtcDesignator::Z,
+    };
+}
+
+impl Default for Iso8601Options {
+    fn default() -> Self {
+        Iso8601Options::RFC3339
+    }
+}
+
+impl FirstDate {
+    /// Formats the date as an RFC 3339 timestamp with milliseconds and the UTC offset (including daylight savings time), for example "2025-05-23T03:46:48.447Z" or "2025-05-22T22:46:48.447-05:00".
+    pub fn to_rfc3339(&self) -> String {
+        self.to_iso8601(&Iso8601Options::RFC3339)
+    }
+
+    /// Formats the date as an ISO 8601 date and time with the UTC offset (including daylight savings time), see `Iso8601Options` for the choices.
+    pub fn to_iso8601(&self, options: &Iso8601Options) -> String {
+        let (date_separator, time_separator) = match options.format {
+            IsoFormat::Basic => ("", ""),
+            IsoFormat::Extended => ("-", ":"),
+        };
+
+        let mut iso_string = format!(
+            "{year}{ds}{month:02}{ds}{day:02}T{hour:02}{ts}{minute:02}{ts}{second:02}",
+            year = format_iso_year(self.year as i64),
+            month = self.month,
+            day = self.day,
+            hour = self.hour,
+            minute = self.minute,
+            second = self.second,
+            ds = date_separator,
+            ts = time_separator,
+        );
+
+        let fraction_digits = options.fraction_digits.min(9) as usize;
+        if fraction_digits > 0 {
+            let fraction = format!("{:03}000000", self.millisecond);
+            iso_string.push('.');
+            iso_string.push_str(&fraction[..fraction_digits]);
+        }
+
+        let offset = total_offset(self);
+        if offset.is_utc() && options.utc_designator == UtcDesignator::Z {
+            iso_string.push('Z');
+        } else {
+            iso_string.push_str(&offset.to_string().replace(':', time_separator));
+        }
+        iso_string
+    }
+
+    /// Parses an RFC 3339 timestamp, for example "2025-05-23T03:46:48.447Z" or "2025-05-22T22:46:48-05:00". The date takes the offset of the timestamp (without a timezone name). Digits of the fractional seconds past the milliseconds are dropped.
+    pub fn parse_rfc3339(value: &str) -> Result<FirstDate, ParseError> {
+        let mut scanner = Scanner::new(value);
+        let year = scanner.number(4, 4, "year")?;
+        scanner.expect('-', "'-'")?;
+        let date = scan_calendar_date(&mut scanner, year, true)?;
+        if scanner.eat_any(&['T', 't', ' ']).is_none() {
+            return Err(scanner.unexpected("'T'"));
+        }
+
+        let hour = scanner.field(2, "hour", 0, 23)?;
+        scanner.expect(':', "':'")?;
+        let minute = scanner.field(2, "minute", 0, 59)?;
+        scanner.expect(':', "':'")?;
+        let second = scanner.field(2, "second", 0, 59)?;
+        let millisecond = if scanner.eat('.') {
+            scanner.fraction_millis()?
+        } else {
+            0
+        };
+
+        let offset = if scanner.eat_any(&['Z', 'z']).is_some() {
+            UtcOffset::UTC
+        } else {
+            let sign = scanner
+                .eat_any(&['+', '-'])
+                .ok_or_else(|| scanner.unexpected("UTC offset"))?;
+            let hours = scanner.field(2, "offset hours", 0, 23)?;
+            scanner.expect(':', "':'")?;
+            let minutes = scanner.field(2, "offset minutes", 0, 59)?;
+            offset_of(sign, hours, minutes)
+        };
+        scanner.finish()?;
+
+        let time = (hour as u8, minute as u8, second as u8, millisecond);
+        Ok(with_time(&date, time, offset))
+    }
+
+    /// Parses the common ISO 8601 formats, in the extended or basic format:
+    /// - dates, "2025-05-23" or "20250523"
+    /// - week dates, "2025-W21-5" or "2025W215"
+    /// - ordinal dates, "2025-143" or "2025143"
+    /// - any of the dates followed by a time "T03:46", "T03:46:48", "T03:46:48.447" (or "T034648,447"), and an optional offset "Z", "+05:30", "+0530" or "+05".
+    ///
+    /// Without an offset the time is taken as UTC. Years outside of 0 to 9999 need a sign, for example "-0043-03-15".
+    pub fn parse_iso8601(value: &str) -> Result<FirstDate, ParseError> {
+        let mut scanner = Scanner::new(value);
+        let date = scan_iso_date(&mut scanner)?;
+        if scanner.eat_any(&['T', 't']).is_none() {
+            scanner.finish()?;
+            return Ok(date);
+        }
+
+        let time = scan_iso_time(&mut scanner)?;
+        let offset = if scanner.is_at_end() {
+            UtcOffset::UTC
+        } else {
+            scan_utc_offset(&mut scanner)?
+        };
+        scanner.finish()?;
+        Ok(with_time(&date, time, offset))
+    }
+}
+
+/// Returns the date at the given local time (hour, minute, second, millisecond) and offset.
+pub fn with_time(date: &FirstDate, time: (u8, u8, u8, u16), offset: UtcOffset) -> FirstDate {
+    let (hour, minute, second, millisecond) = time;
+    let local_millis = days_from_civil(date.year as i64, date.month, date.day)
+        * MILLISECONDS_IN_DAY
+        + hour as i64 * MILLISECONDS_IN_HOUR
+        + minute as i64 * MILLISECONDS_IN_MINUTE
+        + second as i64 * 1000
+        + millisecond as i64;
+    let utc_millis = local_millis - offset.whole_seconds() as i64 * 1000;
+    FirstDate::from_instant(Instant::new(utc_millis, offset))
+}
+
+fn offset_of(sign: char, hours: i64, minutes: i64) -> UtcOffset {
+    let seconds = (hours * 3600 + minutes * 60) as i32;
+    let seconds = if sign == '-' { -seconds } else { seconds };
+    // hours and minutes were checked so the offset is less than a day
+    UtcOffset::from_seconds(seconds).unwrap_or(UtcOffset::UTC)
+}
+
+/// Reads an ISO 8601 year: four digits, or a sign and four to six digits.
+pub fn scan_iso_year(scanner: &mut Scanner) -> Result<i64, ParseError> {
+    let start = scanner.position();
+    let year = match scanner.eat_any(&['+', '-']) {
+        Some(sign) => {
+            let year = scanner.number(4, 6, "year")?;
+            if sign == '-' {
+                -year
+            } else {
+                year
+            }
+        }
+        None => scanner.number(4, 4, "year")?,
+    };
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return Err(ParseError::invalid_field(start, "year", year));
+    }
+    Ok(year)
+}
+
+/// Reads an ISO 8601 calendar, week or ordinal date (extended or basic format) into midnight of that day.
+pub fn scan_iso_date(scanner: &mut Scanner) -> Result<FirstDate, ParseError> {
+    let is_expanded_year = matches!(scanner.peek(), Some('+') | Some('-'));
+    let year = scan_iso_year(scanner)?;
+    let extended = scanner.eat('-');
+    if is_expanded_year && !extended {
+        return Err(scanner.unexpected("'-'"));
+    }
+
+    if scanner.peek() == Some('W') {
+        scan_week_date(scanner, year, extended)
+    } else if scanner.digit_count() == 3 {
+        scan_ordinal_date(scanner, year)
+    } else {
+        scan_calendar_date(scanner, year, extended)
+    }
+}
+
+/// Reads the `MM-DD` (or `MMDD` in the basic format) part of a calendar date, after the year.
+pub fn scan_calendar_date(
+    scanner: &mut Scanner,
+    year: i64,
+    extended: bool,
+) -> Result<FirstDate, ParseError> {
+    let month = scanner.field(2, "month", 1, 12)? as u8;
+    if extended {
+        scanner.expect('-', "'-'")?;
+    }
+    let day_start = scanner.position();
+    let day = scanner.field(2, "day", 1, days_in_month(year, month) as i64)?;
+    FirstDate::from_ymd(year as i32, month, day as u8)
+        .map_err(|_| ParseError::invalid_field(day_start, "day", day))
+}
+
+/// Reads the `Www-D` (or `WwwD` in the basic format) part of a week date, after the year.
+pub fn scan_week_date(
+    scanner: &mut Scanner,
+    iso_year: i64,
+    extended: bool,
+) -> Result<FirstDate, ParseError> {
+    scanner.expect('W', "'W'")?;
+    let week_start = scanner.position();
+    let week = scanner.field(2, "iso week", 1, 53)?;
+    if extended {
+        scanner.expect('-', "'-'")?;
+    }
+    let weekday = Weekday::ALL[scanner.field(1, "weekday", 1, 7)? as usize - 1];
+    FirstDate::from_iso_week_date(iso_year as i32, week as u8, weekday)
+        .map_err(|_| ParseError::invalid_field(week_start, "iso week", week))
+}
+
+/// Reads the `DDD` part of an ordinal date, after the year.
+pub fn scan_ordinal_date(scanner: &mut Scanner, year: i64) -> Result<FirstDate, ParseError> {
+    let start = scanner.position();
+    let day_of_year = scanner.field(3, "day of year", 1, 366)?;
+    FirstDate::from_ordinal(year as i32, day_of_year as u16)
+        .map_err(|_| ParseError::invalid_field(start, "day of year", day_of_year))
+}
+
+/// Reads `hh[:mm[:ss[.fff]]]` or the basic `hh[mm[ss[.fff]]]`, the fraction can also use a comma. Returns the hour, minute, second and millisecond.
+fn scan_iso_time(scanner: &mut Scanner) -> Result<(u8, u8, u8, u16), ParseError> {
+    let hour = scanner.field(2, "hour", 0, 23)? as u8;
+    let extended = scanner.peek() == Some(':');
+    let (mut minute, mut second, mut millisecond) = (0, 0, 0);
+    if scanner.eat(':') || (!extended && scanner.peek_is_digit()) {
+        minute = scanner.field(2, "minute", 0, 59)? as u8;
+        if (extended && scanner.eat(':')) || (!extended && scanner.peek_is_digit()) {
+            second = scanner.field(2, "second", 0, 59)? as u8;
+            if scanner.eat_any(&['.', ',']).is_some() {
+                millisecond = scanner.fraction_millis()?;
+            }
+        }
+    }
+    Ok((hour, minute, second, millisecond))
+}
+
+/// Reads `Z`, `±hh`, `±hh:mm` or `±hhmm`.
+pub fn scan_utc_offset(scanner: &mut Scanner) -> Result<UtcOffset, ParseError> {
+    if scanner.eat_any(&['Z', 'z']).is_some() {
+        return Ok(UtcOffset::UTC);
+    }
+    let sign = scanner
+        .eat_any(&['+', '-'])
+        .ok_or_else(|| scanner.unexpected("UTC offset"))?;
+    let hours = scanner.field(2, "offset hours", 0, 23)?;
+    let minutes = if scanner.eat(':') || scanner.peek_is_digit() {
+        scanner.field(2, "offset minutes", 0, 59)?
+    } else {
+        0
+    };
+    Ok(offset_of(sign, hours, minutes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+
+    #[test]
+    fn to_rfc3339_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(sut.to_rfc3339(), "2025-05-23T03:46:48.447Z");
+
+        // Chicago is on daylight savings time in May
+        sut.set_timezone(String::from("America/Chicago"));
+        assert_eq!(sut.to_rfc3339(), "2025-05-22T22:46:48.447-05:00");
+
+        sut.set_timezone(String::from("Asia/Kolkata"));
+        assert_eq!(sut.to_rfc3339(), "2025-05-23T09:16:48.447+05:30");
+    }
+
+    #[test]
+    fn to_iso8601_options_positive() {
+        let mock_millis: isize = 1747972008447;
+        let sut = FirstDate::from_millis(mock_millis);
+        let basic = Iso8601Options {
+            format: IsoFormat::Basic,
+            fraction_digits: 0,
+            utc_designator: UtcDesignator::Z,
+        };
+        assert_eq!(sut.to_iso8601(&basic), "20250523T034648Z");
+
+        let offset = Iso8601Options {
+            fraction_digits: 6,
+            utc_designator: UtcDesignator::Offset,
+            ..Iso8601Options::default()
+        };
+        assert_eq!(sut.to_iso8601(&offset), "2025-05-23T03:46:48.447000+00:00");
+
+        let one_digit = Iso8601Options {
+            fraction_digits: 1,
+            ..Iso8601Options::default()
+        };
+        assert_eq!(sut.to_iso8601(&one_digit), "2025-05-23T03:46:48.4Z");
+    }
+
+    #[test]
+    fn to_iso8601_basic_offset_positive() {
+        let mut sut = FirstDate::from_ymd_hms(2025, 1, 9, 8, 5, 3).unwrap();
+        sut.set_timezone(String::from("Asia/Kathmandu"));
+        let basic = Iso8601Options {
+            format: IsoFormat::Basic,
+            ..Iso8601Options::default()
+        };
+        assert_eq!(sut.to_iso8601(&basic), "20250109T135003.000+0545");
+    }
+
+    #[test]
+    fn to_rfc3339_expanded_year_positive() {
+        let sut = FirstDate::from_ymd(-43, 3, 15).unwrap();
+        assert_eq!(sut.to_rfc3339(), "-0043-03-15T00:00:00.000Z");
+    }
+
+    #[test]
+    fn parse_rfc3339_positive() {
+        let sut = FirstDate::parse_rfc3339("2025-05-23T03:46:48.447Z").unwrap();
+        assert_eq!(sut.to_millis(), 1747972008447);
+        assert_eq!(sut.timezone_offset, Some(UtcOffset::UTC));
+
+        let sut = FirstDate::parse_rfc3339("2025-05-22t22:46:48.447123-05:00").unwrap();
+        assert_eq!(sut.to_millis(), 1747972008447);
+        assert_eq!((sut.day, sut.hour), (22, 22));
+        assert_eq!(sut.to_rfc3339(), "2025-05-22T22:46:48.447-05:00");
+
+        let sut = FirstDate::parse_rfc3339("2025-05-23 09:16:48+05:30").unwrap();
+        assert_eq!(sut.to_millis(), 1747972008000);
+    }
+
+    #[test]
+    fn parse_rfc3339_negative() {
+        assert_eq!(
+            FirstDate::parse_rfc3339("2025-13-23T03:46:48Z").err(),
+            Some(ParseError::invalid_field(5, "month", 13))
+        );
+        assert_eq!(
+            FirstDate::parse_rfc3339("2025-02-29T03:46:48Z").err(),
+            Some(ParseError::invalid_field(8, "day", 29))
+        );
+        assert_eq!(
+            FirstDate::parse_rfc3339("2025-05-23T03:46:48").err(),
+            Some(ParseError::new(
+                19,
+                ParseErrorKind::UnexpectedEnd {
+                    expected: "UTC offset"
+                }
+            ))
+        );
+        assert_eq!(
+            FirstDate::parse_rfc3339("2025-05-23T03:46:48+0500").err(),
+            Some(ParseError::new(
+                22,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: '0',
+                    expected: "':'"
+                }
+            ))
+        );
+        assert_eq!(
+            FirstDate::parse_rfc3339("2025-05-23T03:46:48Zjunk").err(),
+            Some(ParseError::new(20, ParseErrorKind::TrailingCharacters))
+        );
+        assert!(FirstDate::parse_rfc3339("2025-05-23").is_err());
+        assert!(FirstDate::parse_rfc3339("2025-05-23T24:00:00Z").is_err());
+    }
+
+    #[test]
+    fn parse_iso8601_positive() {
+        let expected = FirstDate::from_ymd_hms_milli(2025, 5, 23, 3, 46, 48, 447).unwrap();
+        for value in [
+            "2025-05-23T03:46:48.447Z",
+            "20250523T034648,447Z",
+            "2025-05-23T03:46:48.447+00:00",
+            "2025-05-23T09:16:48.447+05:30",
+            "2025-05-23T09:16:48.447+0530",
+            "2025-05-22T22:46:48.447-05",
+            "2025-W21-5T03:46:48.447",
+            "2025W215T034648.447Z",
+            "2025-143T03:46:48.447Z",
+            "2025143T034648.447",
+        ] {
+            assert_eq!(
+                FirstDate::parse_iso8601(value),
+                Ok(expected.clone()),
+                "{}",
+                value
+            );
+        }
+
+        let sut = FirstDate::parse_iso8601("2025-05-23").unwrap();
+        assert_eq!((sut.year, sut.month, sut.day, sut.hour), (2025, 5, 23, 0));
+        let sut = FirstDate::parse_iso8601("2025-05-23T17:30").unwrap();
+        assert_eq!((sut.hour, sut.minute, sut.second), (17, 30, 0));
+        let sut = FirstDate::parse_iso8601("-0043-03-15T12").unwrap();
+        assert_eq!((sut.year, sut.month, sut.day, sut.hour), (-43, 3, 15, 12));
+        let sut = FirstDate::parse_iso8601("+10000-01-01").unwrap();
+        assert_eq!(sut.year, 10000);
+    }
+
+    #[test]
+    fn parse_iso8601_round_trip_positive() {
+        let mut sut = FirstDate::from_millis(1747972008447);
+        sut.set_timezone(String::from("Asia/Kathmandu"));
+        let basic = Iso8601Options {
+            format: IsoFormat::Basic,
+            ..Iso8601Options::default()
+        };
+        for options in [Iso8601Options::RFC3339, basic] {
+            let parsed = FirstDate::parse_iso8601(&sut.to_iso8601(&options)).unwrap();
+            assert_eq!(parsed, sut);
+            assert_eq!(parsed.timezone_offset, sut.timezone_offset);
+        }
+    }
+
+    #[test]
+    fn parse_iso8601_negative() {
+        assert_eq!(
+            FirstDate::parse_iso8601("2025-W53-1").err(),
+            Some(ParseError::invalid_field(6, "iso week", 53))
+        );
+        assert_eq!(
+            FirstDate::parse_iso8601("2025-366").err(),
+            Some(ParseError::invalid_field(5, "day of year", 366))
+        );
+        assert_eq!(
+            FirstDate::parse_iso8601("2025-05-23T03:61").err(),
+            Some(ParseError::invalid_field(14, "minute", 61))
+        );
+        assert_eq!(
+            FirstDate::parse_iso8601("2025-05-23T03:46:48+25:00").err(),
+            Some(ParseError::invalid_field(20, "offset hours", 25))
+        );
+        assert_eq!(
+            FirstDate::parse_iso8601("+20250523").err(),
+            Some(ParseError::new(
+                7,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: '2',
+                    expected: "'-'"
+                }
+            ))
+        );
+        for value in [
+            "",
+            "2025",
+            "2025-5-23",
+            "2025-05-23T",
+            "2025-05-23T03:46:48.Z",
+            "05/23/2025",
+        ] {
+            assert!(FirstDate::parse_iso8601(value).is_err(), "{}", value);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::date_utilities::{
+    check_field, civil_from_days, days_from_civil, format_iso_year, MAX_YEAR, MIN_YEAR,
+};
+use crate::error::ParseError;
+use crate::iso8601::{scan_iso_year, scan_week_date};
+use crate::scanner::Scanner;
+use crate::{FirstDate, FirstDateError, FirstDateOperations, Weekday};
+use std::fmt;
+
+/// An ISO 8601 week: the week-numbering year and the week number (1 to 52, or 53 in long years).
+/// Weeks start on Monday and week 1 is the week with the year's first Thursday, so the first days of January can belong to the previous ISO year and the last days of December to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoWeek {
+    pub year: i32,
+    pub week: u8,
+}
+
+impl IsoWeek {
+    /// The number of ISO weeks in an ISO year, 53 when the year starts on a Thursday (or on a Wednesday in leap years), otherwise 52.
+    pub fn weeks_in_year(year: i32) -> u8 {
+        // December 28th is always in the last week of its ISO year
+        IsoWeek::of_days(days_from_civil(year as i64, 12, 28)).week
+    }
+
+    /// The ISO week of a number of days since Jan 1 1970.
+    fn of_days(days: i64) -> IsoWeek {
+        let weekday = Weekday::from_days_since_epoch(days);
+        // the week belongs to the year its Thursday is in
+        let thursday = days + Weekday::Thursday.number_from_monday() as i64
+            - weekday.number_from_monday() as i64;
+        let (year, _, _) = civil_from_days(thursday);
+        let week = (thursday - days_from_civil(year, 1, 1)) / 7 + 1;
+        IsoWeek {
+            year: year as i32,
+            week: week as u8,
+        }
+    }
+}
+
+/// Displays as `YYYY-Www`, for example "2025-W21".
+impl fmt::Display for IsoWeek {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-W{:02}", format_iso_year(self.year as i64), self.week)
+    }
+}
+
+impl FirstDate {
+    /// The ISO 8601 week-numbering year and week of the date. For example January 1st 2021 (a Friday) is in week 53 of 2020.
+    pub fn iso_week(&self) -> IsoWeek {
+        IsoWeek::of_days(days_from_civil(self.year as i64, self.month, self.day))
+    }
+
+    /// Creates a new instance at midnight of an ISO 8601 week date. Returns `FirstDateError::InvalidField` if the week does not exist in the ISO year (for example week 53 of 2025).
+    pub fn from_iso_week_date(
+        iso_year: i32,
+        week: u8,
+        weekday: Weekday,
+    ) -> Result<FirstDate, FirstDateError> {
+        check_field(
+            "iso week",
+            week as i64,
+            1,
+            IsoWeek::weeks_in_year(iso_year) as i64,
+        )?;
+        // week 1 is the week with January 4th in it
+        let january_4th = days_from_civil(iso_year as i64, 1, 4);
+        let week_1_monday = january_4th
+            - Weekday::from_days_since_epoch(january_4th).days_since(Weekday::Monday) as i64;
+        let days =
+            week_1_monday + (week as i64 - 1) * 7 + weekday.days_since(Weekday::Monday) as i64;
+        let (year, month, day) = civil_from_days(days);
+        check_field("year", year, MIN_YEAR, MAX_YEAR)?;
+        FirstDate::from_ymd(year as i32, month, day)
+    }
+
+    /// Formats the date as an ISO 8601 week date `YYYY-Www-D`, for example "2025-W21-5" for Friday May 23rd 2025.
+    pub fn iso_week_date_string(&self) -> String {
+        format!(
+            "{}-{}",
+            self.iso_week(),
+            self.weekday().number_from_monday()
+        )
+    }
+
+    /// Parses an ISO 8601 week date `YYYY-Www-D` (the format of `iso_week_date_string`) into midnight of that day.
+    pub fn parse_iso_week_date(value: &str) -> Result<FirstDate, ParseError> {
+        let mut scanner = Scanner::new(value);
+        let iso_year = scan_iso_year(&mut scanner)?;
+        scanner.expect('-', "'-'")?;
+        let date = scan_week_date(&mut scanner, iso_year, true)?;
+        scanner.finish()?;
+        Ok(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iso_week_positive() {
+        let sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        assert_eq!(
+            sut.iso_week(),
+            IsoWeek {
+                year: 2025,
+                week: 21
+            }
+        );
+        assert_eq!(sut.iso_week().to_string(), "2025-W21");
+    }
+
+    #[test]
+    fn iso_week_neighbouring_year_positive() {
+        // Friday January 1st 2021 belongs to the last week of 2020
+        let sut = FirstDate::from_ymd(2021, 1, 1).unwrap();
+        assert_eq!(sut.iso_week_date_string(), "2020-W53-5");
+
+        // Monday December 29th 2025 belongs to the first week of 2026
+        let sut = FirstDate::from_ymd(2025, 12, 29).unwrap();
+        assert_eq!(sut.iso_week_date_string(), "2026-W01-1");
+
+        // Sunday January 1st 2023 is in week 52 of 2022
+        let sut = FirstDate::from_ymd(2023, 1, 1).unwrap();
+        assert_eq!(sut.iso_week_date_string(), "2022-W52-7");
+    }
+
+    #[test]
+    fn weeks_in_year_positive() {
+        assert_eq!(IsoWeek::weeks_in_year(2020), 53);
+        assert_eq!(IsoWeek::weeks_in_year(2015), 53);
+        assert_eq!(IsoWeek::weeks_in_year(2026), 53);
+        assert_eq!(IsoWeek::weeks_in_year(2025), 52);
+        assert_eq!(IsoWeek::weeks_in_year(2021), 52);
+    }
+
+    #[test]
+    fn from_iso_week_date_positive() {
+        let sut = FirstDate::from_iso_week_date(2020, 53, Weekday::Friday).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (2021, 1, 1));
+
+        let sut = FirstDate::from_iso_week_date(2026, 1, Weekday::Monday).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (2025, 12, 29));
+
+        let sut = FirstDate::from_iso_week_date(2025, 21, Weekday::Friday).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day, sut.hour), (2025, 5, 23, 0));
+    }
+
+    #[test]
+    fn from_iso_week_date_negative() {
+        assert_eq!(
+            FirstDate::from_iso_week_date(2025, 53, Weekday::Monday).err(),
+            Some(FirstDateError::InvalidField {
+                field: "iso week",
+                value: 53
+            })
+        );
+        assert!(FirstDate::from_iso_week_date(2025, 0, Weekday::Monday).is_err());
+    }
+
+    #[test]
+    fn iso_week_date_round_trip_positive() {
+        let mut sut = FirstDate::from_ymd(2019, 12, 1).unwrap();
+        for _ in 0..800 {
+            let parsed = FirstDate::parse_iso_week_date(&sut.iso_week_date_string()).unwrap();
+            assert_eq!(parsed, sut);
+            sut.add(1, crate::TimeUnit::Day);
+        }
+
+        let sut = FirstDate::parse_iso_week_date("-0043-W11-5").unwrap();
+        assert_eq!(sut.iso_week_date_string(), "-0043-W11-5");
+    }
+
+    #[test]
+    fn parse_iso_week_date_negative() {
+        for value in [
+            "2025-W21",
+            "2025W215",
+            "2025-W1-5",
+            "2025-W21-8",
+            "2025-W21-0",
+            "2025-W53-1",
+            "25-W21-5",
+            "2025-Wx1-5",
+        ] {
+            assert!(
+                FirstDate::parse_iso_week_date(value).is_err(),
+                "{} should not parse",
+                value
+            );
+        }
+    }
+}
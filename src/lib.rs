@@ -1,226 +1,681 @@
 use crate::date_utilities::{
-    add_day_helper, add_helper, epoch_milliseconds, generate_first_date_from_millis, is_dst,
-    is_leap_year, local_timezone, local_timezone_offset, AddEnum, TimeUnit,
+    check_field, days_from_civil, days_in_month, epoch_milliseconds, epoch_seconds, era_of,
+    format_iso_year, generate_first_date_from_millis, instant_of, local_epoch_millis,
+    local_time_type_at_wall_clock, local_timezone, max_local_millis, millis_from_fields,
+    millis_in_unit, min_local_millis, timezone_info, total_offset, utc_offset_of,
+    MILLISECONDS_IN_DAY,
 };
+use std::cmp::Ordering;
 use std::env::consts::OS;
+use std::ops;
 
+mod date_style;
 mod date_utilities;
+mod duration;
+mod error;
+mod http_date;
+mod instant;
+mod iso8601;
+mod iso_week;
+mod locale;
+mod natural;
+mod ordinal;
+mod period;
+mod relative;
+mod rfc2822;
+mod scanner;
+mod strftime;
+mod strptime;
+mod tzif;
+mod utc_offset;
+mod weekday;
+
+pub use date_style::{ClockFormat, DateOrder, DateStyle, YearFormat};
+pub use date_utilities::{Era, MonthOverflow, TimeUnit, MAX_YEAR, MIN_YEAR};
+pub use duration::Duration;
+pub use error::{FirstDateError, ParseError, ParseErrorKind};
+pub use instant::Instant;
+pub use iso8601::{Iso8601Options, IsoFormat, UtcDesignator};
+pub use iso_week::IsoWeek;
+pub use locale::Locale;
+pub use period::Period;
+pub use relative::{
+    humanize, humanize_with, EnglishRelativeWords, RelativeOptions, RelativeStyle, RelativeWords,
+    Tense,
+};
+pub use strftime::{Padding, Pattern, PatternItem, Specifier};
+pub use tzif::{LeapSecond, LocalTimeType, TimeZoneInfo, Transition, ZONEINFO_DIRECTORY};
+pub use utc_offset::UtcOffset;
+pub use weekday::Weekday;
 
 #[derive(Debug, Clone)]
 pub struct FirstDate {
     pub day: u8,
     pub hour: u8,
     pub month: u8,
-    pub year: u16,
+    /// The year in astronomical numbering, year 0 is 1 BC (see `era` and `year_of_era`).
+    pub year: i32,
     pub minute: u8,
     pub second: u8,
     pub millisecond: u16,
     pub is_leap_year: bool,
     pub month_name: String,
     pub timezone_name: String,
-    pub timezone_offset: Option<i8>,
+    pub timezone_offset: Option<UtcOffset>,
     pub is_daylight_savings: Option<bool>,
 }
 
+/// The core operations of a `FirstDate`, implemented in this file: creating one, converting it to milliseconds or an `Instant`, adding time, timezones and the basic calendar fields (weekday, day of the year, era).
+///
+/// Features that have their own module (the formats and parsers such as `to_rfc3339`, `format` and `parse_natural`, ISO weeks, ordinal dates, relative times and periods) are inherent methods in an `impl FirstDate` block of that module instead, so they can be called without importing this trait. New features follow the same split.
 pub trait FirstDateOperations {
-    /// Creates a new instance with values from the current instant. Note, if you are on a Linux machine, this will account for timezone (via the `/etc/timezone` file). If the library fails to get the timezone from the system or fails in retriving the matching value from the timezone table file, the timezone will not be applied. Otherwise it the timezone will be UTC-0. Also attempts to query daylight savings transition rules (also linux specific) and will fail silently if the operation fails.
+    /// Creates a new instance with values from the current instant. Note, if you are on a Linux machine, this will account for timezone (via the `/etc/timezone` file). If the library fails to get the timezone from the system or fails in reading the timezone's TZif file, the timezone will not be applied. Otherwise it the timezone will be UTC-0. The offset and daylight savings time come from the transition rules of the TZif file (also linux specific).
     fn now() -> Self;
+    /// Same as `now()` but returns an error instead of silently skipping the timezone or daylight savings time when the system timezone or the timezone data can't be read.
+    fn try_now() -> Result<Self, FirstDateError>
+    where
+        Self: Sized;
     /// Creates a new instance with values determined by the number of milliseconds provided.
-    /// Timezone will be UTC-0.
+    /// Timezone will be UTC-0. Milliseconds outside of the supported years (`MIN_YEAR` to `MAX_YEAR`) give the earliest or latest supported moment.
     fn from_millis(millis: isize) -> Self;
-    /// Adds a given amount of time based on the unit. Note: `time_to_add` can be negative time to subtract.
-    fn add(&mut self, time_to_add: i16, unit: TimeUnit);
-    /// Can be used if timezone not set because `now()` was called but not on a compatible linux system or another date method was used (for example `from_millis`) to initiate the first_date.
+    /// Same as `from_millis` but returns `FirstDateError::Overflow` when the milliseconds are outside of the supported years.
+    fn try_from_millis(millis: isize) -> Result<Self, FirstDateError>
+    where
+        Self: Sized;
+    /// Creates a new instance at midnight of the given date. Returns `FirstDateError::InvalidField` if the month or day is invalid (for example February 29th of a non-leap year).
+    /// Timezone will be UTC-0.
+    fn from_ymd(year: i32, month: u8, day: u8) -> Result<Self, FirstDateError>
+    where
+        Self: Sized;
+    /// Creates a new instance at the given date and time (24-hour clock). Returns `FirstDateError::InvalidField` if any of the fields is invalid.
+    /// Timezone will be UTC-0.
+    fn from_ymd_hms(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, FirstDateError>
+    where
+        Self: Sized;
+    /// Same as `from_ymd_hms` with milliseconds (0 to 999).
+    fn from_ymd_hms_milli(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+    ) -> Result<Self, FirstDateError>
+    where
+        Self: Sized;
+    /// Creates a new instance at midnight of the given date using traditional era numbering, for example `from_era_ymd(Era::Bc, 44, 3, 15)`. Returns `FirstDateError::InvalidField` if the year of era is 0.
+    fn from_era_ymd(era: Era, year_of_era: u32, month: u8, day: u8) -> Result<Self, FirstDateError>
+    where
+        Self: Sized;
+    /// Creates a new instance from an `Instant`. The fields are those seen on a wall clock at the instant's offset.
+    fn from_instant(instant: Instant) -> Self;
+    /// Returns the number of milliseconds since Jan 1 1970 UTC, the inverse of `from_millis`. The timezone offset (including daylight savings time) is taken into account, so converting a date to another timezone does not change the result.
+    fn to_millis(&self) -> isize;
+    /// Returns the point in time represented by the date along with its offset from UTC (including daylight savings time).
+    fn instant(&self) -> Instant;
+    /// Adds a given amount of time based on the unit. Note: `time_to_add` can be negative time to subtract. If the result is out of the supported range the date is left unchanged.
+    fn add(&mut self, time_to_add: i64, unit: TimeUnit);
+    /// Same as `add` but returns `FirstDateError::Overflow` when the result is out of the supported range (the checked version of `add`).
+    fn try_add(&mut self, time_to_add: i64, unit: TimeUnit) -> Result<(), FirstDateError>;
+    /// Same as `add` but when the result is out of the supported range the date is set to the earliest or latest supported moment.
+    fn saturating_add(&mut self, time_to_add: i64, unit: TimeUnit);
+    /// Same as `add` but when the result is out of the supported range it wraps around to the other end of the range, like the integer `wrapping_add`.
+    fn wrapping_add(&mut self, time_to_add: i64, unit: TimeUnit);
+    /// Adds (or subtracts, if negative) calendar months. When the day does not exist in the new month (for example January 31st + 1 month) the `policy` decides if the day is clamped to the end of the month, carried into the next month or an error is returned. `add(n, TimeUnit::Month)` uses `MonthOverflow::Clamp`.
+    fn try_add_months(&mut self, months: i64, policy: MonthOverflow) -> Result<(), FirstDateError>;
+    /// Adds (or subtracts, if negative) calendar years, February 29th is handled by the `policy` like in `try_add_months`. `add(n, TimeUnit::Year)` uses `MonthOverflow::Clamp`.
+    fn try_add_years(&mut self, years: i64, policy: MonthOverflow) -> Result<(), FirstDateError>;
+    /// Adds a fixed `Duration` (which can be negative). Returns `FirstDateError::Overflow` when the result is out of the supported range. Also available as the `+` and `-` operators.
+    fn try_add_duration(&mut self, duration: Duration) -> Result<(), FirstDateError>;
+    /// Returns the amount of time from `earlier` to this date, negative if `earlier` is actually later. Also available as the `-` operator.
+    fn duration_since(&self, earlier: &Self) -> Duration;
+    /// Can be used if timezone not set because `now()` was called but not on a compatible linux system or another date method was used (for example `from_millis`) to initiate the first_date. The offset and `is_daylight_savings` are the ones in effect at the date according to the timezone's TZif file. If the timezone is not found, it silently fails.
     fn set_timezone(&mut self, timezone_name: String);
-    /// Can be used if daylight savings not set because `now()` was called but not on a compatible linux system or another date method was used (for example `from_millis`) to initiate the first_date. If the timezone is not found, it silently fails.
+    /// Same as `set_timezone` but returns an error when the timezone is not found or its TZif file can't be read.
+    fn try_set_timezone(&mut self, timezone_name: String) -> Result<(), FirstDateError>;
+    /// Overrides the `is_daylight_savings` flag. The fields and the offset are not changed, so the flag can contradict the offset.
+    #[deprecated(
+        note = "`set_timezone` and every change of the date apply the daylight savings time of the timezone data"
+    )]
     fn set_daylight_savings(&mut self, is_dst: bool);
-    /// This method returns a singular date string and it takes a flag to toggle 24-hour time and 12-hour time (with AM/PM). Follows the pattern MM/dd/YYYY HH:mm:ss (AM/PM if applicable).
-    fn date_string(&self, display_24_hour: bool) -> String;
+    /// Same as `set_daylight_savings`, kept for compatibility. It never fails.
+    #[deprecated(
+        note = "`set_timezone` and every change of the date apply the daylight savings time of the timezone data"
+    )]
+    fn try_set_daylight_savings(&mut self, is_dst: bool) -> Result<(), FirstDateError>;
+    /// Creates a new instance at midnight of the `n`th given weekday of the month, for example the second Tuesday is `n = 2`. Negative values count from the end of the month, the last Friday is `n = -1`. Returns `FirstDateError::InvalidField` if the month has no such day (for example a fifth Monday).
+    fn nth_weekday_of_month(
+        year: i32,
+        month: u8,
+        weekday: Weekday,
+        n: i8,
+    ) -> Result<Self, FirstDateError>
+    where
+        Self: Sized;
+    /// Same as `nth_weekday_of_month` with `n = -1`, for example the last Friday of the month.
+    fn last_weekday_of_month(
+        year: i32,
+        month: u8,
+        weekday: Weekday,
+    ) -> Result<Self, FirstDateError>
+    where
+        Self: Sized;
+    /// The day of the week of the date.
+    fn weekday(&self) -> Weekday;
+    /// The day of the year, from 1 for January 1st to 365 (366 in leap years) for December 31st.
+    fn day_of_year(&self) -> u16;
+    /// Returns the first date strictly after this one that falls on the given weekday, at the same time of day. A Friday's next Friday is a week later.
+    fn next_weekday(&self, weekday: Weekday) -> Self
+    where
+        Self: Sized;
+    /// Returns the last date strictly before this one that falls on the given weekday, at the same time of day.
+    fn previous_weekday(&self, weekday: Weekday) -> Self
+    where
+        Self: Sized;
+    /// The era of the date, `Era::Bc` for year 0 and earlier.
+    fn era(&self) -> Era;
+    /// The year within the era, for example 44 for 44 BC (astronomical year -43).
+    fn year_of_era(&self) -> u32;
+    /// Formats the date for display, see `DateStyle` for the choices and the `US`, `EUROPEAN` and `ISO` presets. BC years are written with their year of era followed by " BC", or as a signed ISO 8601 year with `YearFormat::Iso` (the `ISO` preset).
+    fn date_string(&self, style: &DateStyle) -> String;
 }
 
 impl Default for FirstDate {
     fn default() -> FirstDate {
         FirstDate {
-            day: 0,
+            day: 1,
             hour: 0,
-            year: 0,
+            year: 1970,
             month: 1,
             minute: 0,
             second: 0,
             millisecond: 0,
             is_leap_year: false,
-            timezone_offset: Some(0),
+            timezone_offset: Some(UtcOffset::UTC),
             is_daylight_savings: Some(false),
-            month_name: String::from("Januray"),
+            month_name: String::from("January"),
             timezone_name: String::from("unset"),
         }
     }
 }
 
+impl FirstDate {
+    /// Moves the date by a fixed amount of time. The fields are derived again from the shifted instant so every unit carries (or borrows) into the next one.
+    fn try_shift_millis(&mut self, millis: i64) -> Result<(), FirstDateError> {
+        let local_millis = local_epoch_millis(self)
+            .checked_add(millis)
+            .ok_or(FirstDateError::Overflow)?;
+        self.try_set_local_millis(local_millis)
+    }
+
+    /// Moves the date by calendar months, keeping the time of day.
+    fn try_add_calendar_months(
+        &mut self,
+        months: i64,
+        policy: MonthOverflow,
+    ) -> Result<(), FirstDateError> {
+        let total_months = (self.year as i64 * 12 + self.month as i64 - 1)
+            .checked_add(months)
+            .ok_or(FirstDateError::Overflow)?;
+        let year = total_months.div_euclid(12);
+        let month = (total_months.rem_euclid(12) + 1) as u8;
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(FirstDateError::Overflow);
+        }
+
+        let last_day = days_in_month(year, month);
+        let mut extra_days = 0;
+        if self.day > last_day {
+            match policy {
+                MonthOverflow::Clamp => {}
+                MonthOverflow::Overflow => extra_days = (self.day - last_day) as i64,
+                MonthOverflow::Error => {
+                    return Err(FirstDateError::InvalidField {
+                        field: "day",
+                        value: self.day as i64,
+                    })
+                }
+            }
+        }
+
+        let time_of_day = local_epoch_millis(self).rem_euclid(MILLISECONDS_IN_DAY);
+        let day = self.day.min(last_day);
+        let local_millis =
+            (days_from_civil(year, month, day) + extra_days) * MILLISECONDS_IN_DAY + time_of_day;
+        self.try_set_local_millis(local_millis)
+    }
+
+    /// Sets every calendar field from milliseconds since Jan 1 1970 (ignoring timezones). When a timezone is set, its offset and daylight savings time at the new wall clock time are applied as well, so moving from January to July in Chicago also moves from -06:00 to -05:00.
+    fn try_set_local_millis(&mut self, local_millis: i64) -> Result<(), FirstDateError> {
+        if self.timezone_name == "unset" {
+            return self.try_set_calendar_fields(local_millis);
+        }
+
+        let zone = timezone_info(&self.timezone_name)?;
+        let local_time_type = local_time_type_at_wall_clock(&zone, local_millis);
+        let timezone_offset = utc_offset_of(&local_time_type, &self.timezone_name)?;
+        self.try_set_calendar_fields(local_millis)?;
+        self.timezone_offset = Some(timezone_offset);
+        self.is_daylight_savings = Some(local_time_type.is_dst);
+        Ok(())
+    }
+
+    /// Sets every calendar field from milliseconds since Jan 1 1970 (ignoring timezones), leaving the timezone fields as they are.
+    fn try_set_calendar_fields(&mut self, local_millis: i64) -> Result<(), FirstDateError> {
+        if !(min_local_millis()..=max_local_millis()).contains(&local_millis) {
+            return Err(FirstDateError::Overflow);
+        }
+
+        let shifted = generate_first_date_from_millis(local_millis as isize);
+        self.day = shifted.day;
+        self.hour = shifted.hour;
+        self.month = shifted.month;
+        self.year = shifted.year;
+        self.minute = shifted.minute;
+        self.second = shifted.second;
+        self.millisecond = shifted.millisecond;
+        self.is_leap_year = shifted.is_leap_year;
+        self.month_name = shifted.month_name;
+        Ok(())
+    }
+}
+
+/// Two dates are equal when they represent the same instant, regardless of their timezones.
+impl PartialEq for FirstDate {
+    fn eq(&self, other: &FirstDate) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+impl Eq for FirstDate {}
+
+impl PartialOrd for FirstDate {
+    fn partial_cmp(&self, other: &FirstDate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FirstDate {
+    fn cmp(&self, other: &FirstDate) -> Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
+
+impl ops::Sub for &FirstDate {
+    type Output = Duration;
+
+    fn sub(self, other: &FirstDate) -> Duration {
+        self.duration_since(other)
+    }
+}
+
+impl ops::Sub for FirstDate {
+    type Output = Duration;
+
+    fn sub(self, other: FirstDate) -> Duration {
+        self.duration_since(&other)
+    }
+}
+
+/// Panics if the result is out of the supported range, use `try_add_duration` to handle that case.
+impl ops::AddAssign<Duration> for FirstDate {
+    fn add_assign(&mut self, duration: Duration) {
+        self.try_add_duration(duration)
+            .expect("date out of range when adding a duration");
+    }
+}
+
+/// Panics if the result is out of the supported range, use `try_add_duration` to handle that case.
+impl ops::SubAssign<Duration> for FirstDate {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self += -duration;
+    }
+}
+
+impl ops::Add<Duration> for FirstDate {
+    type Output = FirstDate;
+
+    fn add(mut self, duration: Duration) -> FirstDate {
+        self += duration;
+        self
+    }
+}
+
+impl ops::Sub<Duration> for FirstDate {
+    type Output = FirstDate;
+
+    fn sub(mut self, duration: Duration) -> FirstDate {
+        self -= duration;
+        self
+    }
+}
+
+impl ops::Add<Duration> for &FirstDate {
+    type Output = FirstDate;
+
+    fn add(self, duration: Duration) -> FirstDate {
+        self.clone() + duration
+    }
+}
+
+impl ops::Sub<Duration> for &FirstDate {
+    type Output = FirstDate;
+
+    fn sub(self, duration: Duration) -> FirstDate {
+        self.clone() - duration
+    }
+}
+
 impl FirstDateOperations for FirstDate {
     fn now() -> Self {
         let epoch_duration = epoch_milliseconds();
         let mut first_date = generate_first_date_from_millis(epoch_duration);
         if OS == "linux" {
-            let local_machine_timezone = local_timezone();
-            first_date.timezone_name = local_machine_timezone.clone();
-            first_date.set_timezone(local_machine_timezone);
-            if is_dst(&first_date).unwrap() {
-                first_date.set_daylight_savings(true);
+            if let Ok(local_machine_timezone) = local_timezone() {
+                first_date.set_timezone(local_machine_timezone);
             }
         }
 
         first_date
     }
 
+    fn try_now() -> Result<Self, FirstDateError> {
+        let epoch_duration = epoch_milliseconds();
+        let mut first_date = generate_first_date_from_millis(epoch_duration);
+        first_date.try_set_timezone(local_timezone()?)?;
+        Ok(first_date)
+    }
+
     fn from_millis(millis: isize) -> Self {
-        return generate_first_date_from_millis(millis);
+        generate_first_date_from_millis(millis)
+    }
+
+    fn try_from_millis(millis: isize) -> Result<Self, FirstDateError> {
+        if !(min_local_millis()..=max_local_millis()).contains(&(millis as i64)) {
+            return Err(FirstDateError::Overflow);
+        }
+        Ok(generate_first_date_from_millis(millis))
+    }
+
+    fn from_ymd(year: i32, month: u8, day: u8) -> Result<Self, FirstDateError> {
+        Self::from_ymd_hms_milli(year, month, day, 0, 0, 0, 0)
+    }
+
+    fn from_ymd_hms(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, FirstDateError> {
+        Self::from_ymd_hms_milli(year, month, day, hour, minute, second, 0)
+    }
+
+    fn from_ymd_hms_milli(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+    ) -> Result<Self, FirstDateError> {
+        let millis =
+            millis_from_fields(year as i64, month, day, hour, minute, second, millisecond)?;
+        Ok(generate_first_date_from_millis(millis as isize))
+    }
+
+    fn from_era_ymd(
+        era: Era,
+        year_of_era: u32,
+        month: u8,
+        day: u8,
+    ) -> Result<Self, FirstDateError> {
+        // BC goes one further than AD because year 0 is 1 BC, MIN_YEAR is 1000000 BC
+        let max_year_of_era = match era {
+            Era::Ad => MAX_YEAR,
+            Era::Bc => 1 - MIN_YEAR,
+        };
+        check_field("year of era", year_of_era as i64, 1, max_year_of_era)?;
+        let year = match era {
+            Era::Ad => year_of_era as i32,
+            Era::Bc => 1 - year_of_era as i32,
+        };
+        Self::from_ymd(year, month, day)
+    }
+
+    fn from_instant(instant: Instant) -> Self {
+        let mut first_date = generate_first_date_from_millis(instant.local_millis() as isize);
+        first_date.timezone_offset = Some(instant.offset());
+        first_date
+    }
+
+    fn to_millis(&self) -> isize {
+        self.instant().millis() as isize
+    }
+
+    fn instant(&self) -> Instant {
+        instant_of(self)
     }
 
-    fn add(&mut self, time_to_add: i16, unit: TimeUnit) {
+    fn add(&mut self, time_to_add: i64, unit: TimeUnit) {
+        let _ = self.try_add(time_to_add, unit);
+    }
+
+    fn try_add(&mut self, time_to_add: i64, unit: TimeUnit) -> Result<(), FirstDateError> {
         match unit {
-            TimeUnit::Day => {
-                let new_day_result = add_day_helper(
-                    self.day as i16,
-                    time_to_add,
-                    self.month as i8,
-                    self.is_leap_year,
-                );
-                match new_day_result {
-                    AddEnum::SameUnit(day_value, _) => self.day = day_value as u8,
-                    AddEnum::NextUnit(day_value, month_index_value, _) => {
-                        self.day = day_value as u8;
-                        self.add(month_index_value as i16, TimeUnit::Month);
-                    }
-                }
-            }
-            TimeUnit::Month => {
-                let months_in_year = 12;
-                let new_month_value = add_helper(self.month as i16, time_to_add, months_in_year);
-                match new_month_value {
-                    AddEnum::SameUnit(month_value, month_name) => {
-                        self.month = month_value as u8;
-                        self.month_name = month_name.unwrap();
-                    }
-                    AddEnum::NextUnit(month_value, year_value, month_name) => {
-                        self.month_name = month_name.unwrap();
-                        self.month = month_value as u8;
-                        self.add(year_value as i16, TimeUnit::Year);
-                    }
-                }
-            }
-            TimeUnit::Year => {
-                let current_year = self.year as i16;
-                self.year = (current_year + time_to_add) as u16; // Would be cool to handle BC/AD at some point
-                self.is_leap_year = is_leap_year(self.year);
-                // Known issue: add day from any new leap years
+            TimeUnit::Month => self.try_add_months(time_to_add, MonthOverflow::Clamp),
+            TimeUnit::Year => self.try_add_years(time_to_add, MonthOverflow::Clamp),
+            _ => {
+                let millis = millis_in_unit(unit)
+                    .and_then(|unit_millis| time_to_add.checked_mul(unit_millis))
+                    .ok_or(FirstDateError::Overflow)?;
+                self.try_shift_millis(millis)
             }
-            TimeUnit::Hour => {
-                let hours_in_day = 24;
-                let new_hour_value = add_helper(self.hour as i16, time_to_add, hours_in_day);
-                match new_hour_value {
-                    AddEnum::SameUnit(hour_value, _) => self.hour = hour_value as u8,
-                    AddEnum::NextUnit(hour_value, day_value, _) => {
-                        self.hour = hour_value as u8;
-                        self.add(day_value as i16, TimeUnit::Day);
-                    }
-                }
-            }
-            TimeUnit::Minute => {
-                let minutes_in_hour = 60;
-                let new_minute_value = add_helper(self.minute as i16, time_to_add, minutes_in_hour);
-                match new_minute_value {
-                    AddEnum::SameUnit(minute_value, _) => self.minute = minute_value as u8,
-                    AddEnum::NextUnit(minute_value, hour_value, _) => {
-                        self.minute = minute_value as u8;
-                        self.add(hour_value as i16, TimeUnit::Hour);
-                    }
-                }
-            }
-            TimeUnit::Second => {
-                let seconds_in_hour = 60;
-                let new_second_value = add_helper(self.second as i16, time_to_add, seconds_in_hour);
-                match new_second_value {
-                    AddEnum::SameUnit(second_value, _) => self.second = second_value as u8,
-                    AddEnum::NextUnit(second_value, minute_value, _) => {
-                        self.second = second_value as u8;
-                        self.add(minute_value as i16, TimeUnit::Minute);
-                    }
-                }
+        }
+    }
+
+    fn saturating_add(&mut self, time_to_add: i64, unit: TimeUnit) {
+        if self.try_add(time_to_add, unit).is_err() {
+            let limit = if time_to_add < 0 {
+                min_local_millis()
+            } else {
+                max_local_millis()
+            };
+            let _ = self.try_set_local_millis(limit);
+        }
+    }
+
+    fn wrapping_add(&mut self, time_to_add: i64, unit: TimeUnit) {
+        // wrap in i128 so even i64::MAX of the largest unit can't overflow
+        let _ = match millis_in_unit(unit) {
+            Some(unit_millis) => {
+                let first = min_local_millis() as i128;
+                let span = max_local_millis() as i128 - first + 1;
+                let target =
+                    local_epoch_millis(self) as i128 + time_to_add as i128 * unit_millis as i128;
+                self.try_set_local_millis((first + (target - first).rem_euclid(span)) as i64)
             }
-            TimeUnit::Millisecond => {
-                let milliseconds_in_hour = 1000;
-                let new_millisecond_value =
-                    add_helper(self.millisecond as i16, time_to_add, milliseconds_in_hour);
-                match new_millisecond_value {
-                    AddEnum::SameUnit(millisecond_value, _) => {
-                        self.millisecond = millisecond_value as u16
-                    }
-                    AddEnum::NextUnit(millisecond_value, second_value, _) => {
-                        self.millisecond = millisecond_value as u16;
-                        self.add(second_value as i16, TimeUnit::Second);
-                    }
-                }
+            None => {
+                let unit_months = if unit == TimeUnit::Year { 12 } else { 1 };
+                let first = MIN_YEAR as i128 * 12;
+                let span = (MAX_YEAR - MIN_YEAR + 1) as i128 * 12;
+                let current = self.year as i128 * 12 + self.month as i128 - 1;
+                let target = current + time_to_add as i128 * unit_months;
+                let wrapped = first + (target - first).rem_euclid(span);
+                self.try_add_calendar_months((wrapped - current) as i64, MonthOverflow::Clamp)
             }
-        }
+        };
+    }
+
+    fn try_add_months(&mut self, months: i64, policy: MonthOverflow) -> Result<(), FirstDateError> {
+        self.try_add_calendar_months(months, policy)
+    }
+
+    fn try_add_years(&mut self, years: i64, policy: MonthOverflow) -> Result<(), FirstDateError> {
+        let months = years.checked_mul(12).ok_or(FirstDateError::Overflow)?;
+        self.try_add_calendar_months(months, policy)
+    }
+
+    fn try_add_duration(&mut self, duration: Duration) -> Result<(), FirstDateError> {
+        self.try_shift_millis(duration.whole_milliseconds())
+    }
+
+    fn duration_since(&self, earlier: &Self) -> Duration {
+        let difference = self.instant().millis() as i128 - earlier.instant().millis() as i128;
+        Duration::milliseconds(difference.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
     }
 
     fn set_timezone(&mut self, timezone_name: String) {
-        let timezone_offset = local_timezone_offset(timezone_name).unwrap();
-        self.add(timezone_offset, TimeUnit::Hour);
-        self.timezone_offset = Some(timezone_offset as i8);
+        let _ = self.try_set_timezone(timezone_name);
+    }
+
+    fn try_set_timezone(&mut self, timezone_name: String) -> Result<(), FirstDateError> {
+        let zone = timezone_info(&timezone_name)?;
+        let local_time_type = zone.local_time_type_at(epoch_seconds(self));
+        let timezone_offset = utc_offset_of(&local_time_type, &timezone_name)?;
+        // only move the fields by the difference in case a timezone was already applied
+        let difference = timezone_offset.whole_seconds() - total_offset(self).whole_seconds();
+        self.try_set_calendar_fields(local_epoch_millis(self) + difference as i64 * 1000)?;
+        self.timezone_offset = Some(timezone_offset);
+        self.is_daylight_savings = Some(local_time_type.is_dst);
+        self.timezone_name = timezone_name;
+        Ok(())
     }
 
     fn set_daylight_savings(&mut self, is_dst: bool) {
-        match is_dst {
-            true => {
-                if self.is_daylight_savings == None || !self.is_daylight_savings.unwrap() {
-                    self.is_daylight_savings = Some(true);
-                    self.add(1, TimeUnit::Hour);
-                }
-            }
-            false => {
-                if self.is_daylight_savings.unwrap() {
-                    self.is_daylight_savings = Some(false);
-                    self.add(-1, TimeUnit::Hour);
-                }
+        self.is_daylight_savings = Some(is_dst);
+    }
+
+    fn try_set_daylight_savings(&mut self, is_dst: bool) -> Result<(), FirstDateError> {
+        self.is_daylight_savings = Some(is_dst);
+        Ok(())
+    }
+
+    fn nth_weekday_of_month(
+        year: i32,
+        month: u8,
+        weekday: Weekday,
+        n: i8,
+    ) -> Result<Self, FirstDateError> {
+        let invalid_n = FirstDateError::InvalidField {
+            field: "nth weekday",
+            value: n as i64,
+        };
+        let first_of_month = Self::from_ymd(year, month, 1)?;
+        let first_match = 1 + weekday.days_since(first_of_month.weekday());
+        let last_day = days_in_month(year as i64, month);
+        let matches = (last_day - first_match) / 7 + 1;
+        let day = match n {
+            1..=5 if n as u8 <= matches => first_match + (n as u8 - 1) * 7,
+            -5..=-1 if n.unsigned_abs() <= matches => {
+                first_match + (matches - n.unsigned_abs()) * 7
             }
-        }
+            _ => return Err(invalid_n),
+        };
+        Self::from_ymd(year, month, day)
     }
 
-    fn date_string(&self, display_24_hour: bool) -> String {
-        let mut date_string: String = String::new();
-        if self.month < 10 {
-            date_string.push_str(format!("0{}", self.month).as_str());
-        } else {
-            date_string.push_str(format!("{}", self.month).as_str());
-        }
+    fn last_weekday_of_month(
+        year: i32,
+        month: u8,
+        weekday: Weekday,
+    ) -> Result<Self, FirstDateError> {
+        Self::nth_weekday_of_month(year, month, weekday, -1)
+    }
 
-        date_string.push('/');
-        if self.day < 10 {
-            date_string.push_str(format!("0{}", self.day).as_str());
-        } else {
-            date_string.push_str(format!("{}", self.day).as_str());
-        }
+    fn weekday(&self) -> Weekday {
+        Weekday::from_days_since_epoch(days_from_civil(self.year as i64, self.month, self.day))
+    }
 
-        date_string.push('/');
-        date_string.push_str(self.year.to_string().as_str());
-        date_string.push(' ');
+    fn day_of_year(&self) -> u16 {
+        let days = days_from_civil(self.year as i64, self.month, self.day);
+        (days - days_from_civil(self.year as i64, 1, 1) + 1) as u16
+    }
 
-        if !display_24_hour {
-            date_string.push_str(self.hour.to_string().as_str());
-        } else {
-            date_string.push_str((self.hour % 12).to_string().as_str());
-        }
-        date_string.push(':');
+    fn next_weekday(&self, weekday: Weekday) -> Self {
+        let days = match weekday.days_since(self.weekday()) {
+            0 => 7,
+            days => days,
+        };
+        let mut next = self.clone();
+        next.add(days as i64, TimeUnit::Day);
+        next
+    }
+
+    fn previous_weekday(&self, weekday: Weekday) -> Self {
+        let days = match self.weekday().days_since(weekday) {
+            0 => 7,
+            days => days,
+        };
+        let mut previous = self.clone();
+        previous.add(-(days as i64), TimeUnit::Day);
+        previous
+    }
 
-        date_string.push_str(self.minute.to_string().as_str());
-        date_string.push(':');
-        date_string.push_str(self.second.to_string().as_str());
+    fn era(&self) -> Era {
+        era_of(self.year as i64).0
+    }
+
+    fn year_of_era(&self) -> u32 {
+        era_of(self.year as i64).1 as u32
+    }
 
-        if display_24_hour {
+    fn date_string(&self, style: &DateStyle) -> String {
+        let pad = |value: u8| {
+            if style.zero_pad {
+                format!("{:02}", value)
+            } else {
+                value.to_string()
+            }
+        };
+
+        let year = match style.year_format {
+            YearFormat::Era if self.era() == Era::Bc => format!("{} BC", self.year_of_era()),
+            YearFormat::Era => self.year_of_era().to_string(),
+            YearFormat::Iso => format_iso_year(self.year as i64),
+        };
+        let (month, day) = (pad(self.month), pad(self.day));
+        let date_parts = match style.order {
+            DateOrder::Mdy => [month, day, year],
+            DateOrder::Dmy => [day, month, year],
+            DateOrder::Ymd => [year, month, day],
+        };
+
+        let hour = match style.clock {
+            ClockFormat::TwelveHour => match self.hour % 12 {
+                0 => 12,
+                hour => hour,
+            },
+            ClockFormat::TwentyFourHour => self.hour,
+        };
+        let mut date_string = format!(
+            "{} {}{ts}{:02}{ts}{:02}",
+            date_parts.join(&style.date_separator),
+            pad(hour),
+            self.minute,
+            self.second,
+            ts = style.time_separator
+        );
+
+        if style.show_milliseconds {
+            date_string.push_str(&format!(".{:03}", self.millisecond));
+        }
+        if style.clock == ClockFormat::TwelveHour {
+            date_string.push_str(if self.hour < 12 { " AM" } else { " PM" });
+        }
+        if style.show_offset {
             date_string.push(' ');
-            date_string.push_str(if self.hour < 12 { "AM" } else { "PM" });
+            date_string.push_str(&total_offset(self).to_string());
         }
 
         date_string
@@ -230,6 +685,8 @@ impl FirstDateOperations for FirstDate {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::date_utilities::{is_leap_year, MONTHS};
+    use std::borrow::Cow;
 
     #[test]
     fn add_year_positive_leap_false() {
@@ -237,7 +694,7 @@ mod tests {
         let mut sut = FirstDate::from_millis(mock_millis);
         sut.add(1, TimeUnit::Year);
         assert_eq!(sut.year, 2026);
-        assert_eq!(sut.is_leap_year, false);
+        assert!(!sut.is_leap_year);
     }
 
     #[test]
@@ -246,7 +703,7 @@ mod tests {
         let mut sut = FirstDate::from_millis(mock_millis);
         sut.add(3, TimeUnit::Year);
         assert_eq!(sut.year, 2028);
-        assert_eq!(sut.is_leap_year, true);
+        assert!(sut.is_leap_year);
     }
 
     #[test]
@@ -255,7 +712,7 @@ mod tests {
         let mut sut = FirstDate::from_millis(mock_millis);
         sut.add(-1, TimeUnit::Year);
         assert_eq!(sut.year, 2024, "Year incorrect");
-        assert_eq!(sut.is_leap_year, true, "Leap incorrect");
+        assert!(sut.is_leap_year, "Leap incorrect");
     }
 
     #[test]
@@ -370,17 +827,789 @@ mod tests {
     }
 
     #[test]
+    fn set_timezone_half_hour_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        sut.set_timezone(String::from("Asia/Kolkata"));
+        assert_eq!(sut.hour, 9);
+        assert_eq!(sut.minute, 16);
+        assert_eq!(sut.timezone_offset.unwrap().to_string(), "+05:30");
+    }
+
+    #[test]
+    fn set_timezone_quarter_hour_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        sut.set_timezone(String::from("Asia/Kathmandu"));
+        assert_eq!(sut.hour, 9);
+        assert_eq!(sut.minute, 31);
+        assert_eq!(sut.timezone_offset.unwrap().to_string(), "+05:45");
+    }
+
+    #[test]
+    fn to_millis_round_trip_positive() {
+        let mock_millis: isize = 1747972008447;
+        let sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(sut.to_millis(), mock_millis);
+    }
+
+    #[test]
+    fn to_millis_ignores_timezone_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        sut.set_timezone(String::from("Australia/Lord_Howe"));
+        assert_eq!(sut.to_millis(), mock_millis);
+        assert_eq!(sut.instant().offset().to_string(), "+10:30");
+
+        // Lord Howe Island moves its clocks by half an hour for daylight savings time
+        let mut sut = FirstDate::from_ymd(2025, 1, 15).unwrap();
+        sut.set_timezone(String::from("Australia/Lord_Howe"));
+        assert_eq!(sut.is_daylight_savings, Some(true));
+        assert_eq!(sut.instant().offset().to_string(), "+11:00");
+        assert_eq!((sut.hour, sut.minute), (11, 0));
+    }
+
+    #[test]
+    fn try_from_millis_positive() {
+        let sut = FirstDate::try_from_millis(1747972008447).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (2025, 5, 23));
+        let sut = FirstDate::try_from_millis(min_local_millis() as isize).unwrap();
+        assert_eq!((sut.year as i64, sut.month, sut.day), (MIN_YEAR, 1, 1));
+        let sut = FirstDate::try_from_millis(max_local_millis() as isize).unwrap();
+        assert_eq!((sut.year as i64, sut.month, sut.day), (MAX_YEAR, 12, 31));
+    }
+
+    #[test]
+    fn try_from_millis_negative() {
+        let too_early = min_local_millis() as isize - 1;
+        assert_eq!(
+            FirstDate::try_from_millis(too_early).unwrap_err(),
+            FirstDateError::Overflow
+        );
+        assert_eq!(
+            FirstDate::try_from_millis(isize::MAX).unwrap_err(),
+            FirstDateError::Overflow
+        );
+
+        // from_millis stops at the earliest and latest supported moments instead
+        let sut = FirstDate::from_millis(too_early);
+        assert_eq!(sut.to_millis(), min_local_millis() as isize);
+        let sut = FirstDate::from_millis(isize::MAX);
+        assert_eq!(sut.to_millis(), max_local_millis() as isize);
+    }
+
+    #[test]
+    fn from_instant_positive() {
+        let offset = UtcOffset::from_hms(-6, 0, 0).unwrap();
+        let sut = FirstDate::from_instant(Instant::new(1747972008447, offset));
+        assert_eq!(sut.day, 22);
+        assert_eq!(sut.hour, 21);
+        assert_eq!(sut.timezone_offset, Some(offset));
+        assert_eq!(sut.to_millis(), 1747972008447);
+    }
+
+    #[test]
+    fn comparison_positive() {
+        let mock_millis: isize = 1747972008447;
+        let earlier = FirstDate::from_millis(mock_millis);
+        let mut later = FirstDate::from_millis(mock_millis);
+        later.add(1, TimeUnit::Millisecond);
+        assert!(earlier < later);
+
+        let mut same_instant = FirstDate::from_millis(mock_millis);
+        same_instant.set_timezone(String::from("Asia/Kolkata"));
+        assert_eq!(earlier, same_instant);
+    }
+
+    #[test]
+    fn add_hour_rollover_to_day_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        sut.add(-4, TimeUnit::Hour);
+        assert_eq!(sut.hour, 23);
+        assert_eq!(sut.day, 22);
+        assert_eq!(sut.to_millis(), mock_millis - 4 * 3600 * 1000);
+    }
+
+    type Fields = (i32, u8, u8, u8, u8, u8, u16);
+
+    fn fields_of(date: &FirstDate) -> Fields {
+        (
+            date.year,
+            date.month,
+            date.day,
+            date.hour,
+            date.minute,
+            date.second,
+            date.millisecond,
+        )
+    }
+
+    #[test]
+    fn subtract_borrow_table_positive() {
+        // (start, amount, unit, expected)
+        let cases: [(Fields, i64, TimeUnit, Fields); 18] = [
+            (
+                (2025, 5, 23, 3, 0, 0, 5),
+                -10,
+                TimeUnit::Millisecond,
+                (2025, 5, 23, 2, 59, 59, 995),
+            ),
+            (
+                (2025, 1, 1, 0, 0, 0, 0),
+                -1,
+                TimeUnit::Millisecond,
+                (2024, 12, 31, 23, 59, 59, 999),
+            ),
+            (
+                (2025, 5, 23, 3, 0, 5, 0),
+                -20,
+                TimeUnit::Second,
+                (2025, 5, 23, 2, 59, 45, 0),
+            ),
+            (
+                (2025, 3, 1, 0, 0, 10, 0),
+                -11,
+                TimeUnit::Second,
+                (2025, 2, 28, 23, 59, 59, 0),
+            ),
+            (
+                (2025, 5, 23, 3, 5, 0, 0),
+                -20,
+                TimeUnit::Minute,
+                (2025, 5, 23, 2, 45, 0, 0),
+            ),
+            (
+                (2025, 5, 1, 0, 5, 0, 0),
+                -125,
+                TimeUnit::Minute,
+                (2025, 4, 30, 22, 0, 0, 0),
+            ),
+            (
+                (2025, 5, 23, 3, 0, 0, 0),
+                -4,
+                TimeUnit::Hour,
+                (2025, 5, 22, 23, 0, 0, 0),
+            ),
+            (
+                (2024, 3, 1, 1, 0, 0, 0),
+                -2,
+                TimeUnit::Hour,
+                (2024, 2, 29, 23, 0, 0, 0),
+            ),
+            (
+                (2025, 5, 3, 12, 0, 0, 0),
+                -10,
+                TimeUnit::Day,
+                (2025, 4, 23, 12, 0, 0, 0),
+            ),
+            (
+                (2024, 3, 3, 0, 0, 0, 0),
+                -3,
+                TimeUnit::Day,
+                (2024, 2, 29, 0, 0, 0, 0),
+            ),
+            (
+                (2023, 3, 3, 0, 0, 0, 0),
+                -3,
+                TimeUnit::Day,
+                (2023, 2, 28, 0, 0, 0, 0),
+            ),
+            (
+                (2025, 1, 5, 0, 0, 0, 0),
+                -5,
+                TimeUnit::Day,
+                (2024, 12, 31, 0, 0, 0, 0),
+            ),
+            (
+                (2025, 1, 15, 0, 0, 0, 0),
+                -366,
+                TimeUnit::Day,
+                (2024, 1, 15, 0, 0, 0, 0),
+            ),
+            (
+                (2025, 2, 15, 0, 0, 0, 0),
+                -3,
+                TimeUnit::Month,
+                (2024, 11, 15, 0, 0, 0, 0),
+            ),
+            (
+                (2024, 3, 31, 8, 0, 0, 0),
+                -1,
+                TimeUnit::Month,
+                (2024, 2, 29, 8, 0, 0, 0),
+            ),
+            (
+                (2025, 3, 31, 8, 0, 0, 0),
+                -1,
+                TimeUnit::Month,
+                (2025, 2, 28, 8, 0, 0, 0),
+            ),
+            (
+                (2025, 5, 23, 0, 0, 0, 0),
+                -25,
+                TimeUnit::Year,
+                (2000, 5, 23, 0, 0, 0, 0),
+            ),
+            (
+                (2028, 2, 29, 0, 0, 0, 0),
+                -4,
+                TimeUnit::Year,
+                (2024, 2, 29, 0, 0, 0, 0),
+            ),
+        ];
+
+        for (start, amount, unit, expected) in cases {
+            let (year, month, day, hour, minute, second, millisecond) = start;
+            let mut sut =
+                FirstDate::from_ymd_hms_milli(year, month, day, hour, minute, second, millisecond)
+                    .unwrap();
+            sut.add(amount, unit);
+            assert_eq!(
+                fields_of(&sut),
+                expected,
+                "{:?} {:?} from {:?}",
+                amount,
+                unit,
+                start
+            );
+            assert_eq!(sut.month_name, MONTHS[expected.1 as usize - 1].0);
+            assert_eq!(sut.is_leap_year, is_leap_year(expected.0 as i64));
+        }
+    }
+
+    #[test]
+    fn subtract_then_add_round_trip_positive() {
+        let units = [
+            TimeUnit::Millisecond,
+            TimeUnit::Second,
+            TimeUnit::Minute,
+            TimeUnit::Hour,
+            TimeUnit::Day,
+        ];
+        for unit in units {
+            for amount in [1, 7, 59, 61, 1000, 100_000] {
+                let start = FirstDate::from_ymd_hms_milli(2024, 3, 1, 0, 0, 0, 0).unwrap();
+                let mut sut = start.clone();
+                sut.add(-amount, unit);
+                assert!(sut < start);
+                sut.add(amount, unit);
+                assert_eq!(fields_of(&sut), fields_of(&start), "{} {:?}", amount, unit);
+            }
+        }
+    }
+
+    #[test]
+    fn try_add_overflow_negative() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(
+            sut.try_add(-3_000_000, TimeUnit::Year),
+            Err(FirstDateError::Overflow)
+        );
+        assert_eq!(sut.year, 2025);
+
+        sut.add(-3_000_000, TimeUnit::Year);
+        assert_eq!(sut.year, 2025);
+    }
+
+    #[test]
+    fn subtract_year_into_bc_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        sut.add(-3000, TimeUnit::Year);
+        assert_eq!(sut.year, -975);
+        assert_eq!(sut.era(), Era::Bc);
+        assert_eq!(sut.year_of_era(), 976);
+        assert!(sut.to_millis() < 0);
+
+        sut.add(3000, TimeUnit::Year);
+        assert_eq!(sut.to_millis(), mock_millis);
+    }
+
+    #[test]
+    fn from_era_ymd_positive() {
+        let sut = FirstDate::from_era_ymd(Era::Bc, 44, 3, 15).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (-43, 3, 15));
+        assert_eq!(sut.era(), Era::Bc);
+        assert_eq!(sut.year_of_era(), 44);
+
+        let sut = FirstDate::from_era_ymd(Era::Bc, 1, 2, 29).unwrap();
+        assert_eq!(sut.year, 0);
+        assert!(sut.is_leap_year);
+
+        let sut = FirstDate::from_era_ymd(Era::Ad, 2025, 5, 23).unwrap();
+        assert_eq!(sut, FirstDate::from_ymd(2025, 5, 23).unwrap());
+        assert_eq!(sut.era(), Era::Ad);
+
+        let sut = FirstDate::from_era_ymd(Era::Bc, 1_000_000, 1, 1).unwrap();
+        assert_eq!(sut.year as i64, MIN_YEAR);
+        assert_eq!(sut.year_of_era(), 1_000_000);
+    }
+
+    #[test]
+    fn from_era_ymd_negative() {
+        assert_eq!(
+            FirstDate::from_era_ymd(Era::Bc, 0, 1, 1).err(),
+            Some(FirstDateError::InvalidField {
+                field: "year of era",
+                value: 0
+            })
+        );
+        assert!(FirstDate::from_era_ymd(Era::Ad, 1_000_000, 1, 1).is_err());
+        assert_eq!(
+            FirstDate::from_era_ymd(Era::Bc, 1_000_001, 1, 1).err(),
+            Some(FirstDateError::InvalidField {
+                field: "year of era",
+                value: 1_000_001
+            })
+        );
+    }
+
+    #[test]
+    fn weekday_positive() {
+        let mock_millis: isize = 1747972008447;
+        let sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(sut.weekday(), Weekday::Friday);
+        assert_eq!(
+            FirstDate::from_ymd(2000, 1, 1).unwrap().weekday(),
+            Weekday::Saturday
+        );
+        assert_eq!(
+            FirstDate::from_era_ymd(Era::Bc, 1, 1, 1).unwrap().weekday(),
+            Weekday::Saturday
+        );
+    }
+
+    #[test]
+    fn day_of_year_positive() {
+        assert_eq!(FirstDate::from_ymd(2025, 1, 1).unwrap().day_of_year(), 1);
+        assert_eq!(FirstDate::from_ymd(2025, 5, 23).unwrap().day_of_year(), 143);
+        assert_eq!(
+            FirstDate::from_ymd(2025, 12, 31).unwrap().day_of_year(),
+            365
+        );
+        assert_eq!(
+            FirstDate::from_ymd(2024, 12, 31).unwrap().day_of_year(),
+            366
+        );
+    }
+
+    #[test]
+    fn next_previous_weekday_positive() {
+        let sut = FirstDate::from_ymd_hms(2025, 5, 23, 17, 30, 0).unwrap();
+        let next = sut.next_weekday(Weekday::Tuesday);
+        assert_eq!((next.month, next.day, next.hour), (5, 27, 17));
+        let next = sut.next_weekday(Weekday::Friday);
+        assert_eq!((next.month, next.day), (5, 30));
+
+        let previous = sut.previous_weekday(Weekday::Sunday);
+        assert_eq!((previous.month, previous.day), (5, 18));
+        let previous = sut.previous_weekday(Weekday::Friday);
+        assert_eq!((previous.month, previous.day, previous.minute), (5, 16, 30));
+    }
+
+    #[test]
+    fn nth_weekday_of_month_positive() {
+        let sut = FirstDate::nth_weekday_of_month(2025, 5, Weekday::Tuesday, 2).unwrap();
+        assert_eq!((sut.month, sut.day), (5, 13));
+        let sut = FirstDate::last_weekday_of_month(2025, 5, Weekday::Friday).unwrap();
+        assert_eq!(sut.day, 30);
+        let sut = FirstDate::last_weekday_of_month(2025, 2, Weekday::Friday).unwrap();
+        assert_eq!(sut.day, 28);
+        let sut = FirstDate::nth_weekday_of_month(2024, 2, Weekday::Thursday, 5).unwrap();
+        assert_eq!(sut.day, 29);
+        let sut = FirstDate::nth_weekday_of_month(2025, 6, Weekday::Monday, -5).unwrap();
+        assert_eq!(sut.day, 2);
+    }
+
+    #[test]
+    fn nth_weekday_of_month_negative() {
+        assert_eq!(
+            FirstDate::nth_weekday_of_month(2025, 2, Weekday::Monday, 5).err(),
+            Some(FirstDateError::InvalidField {
+                field: "nth weekday",
+                value: 5
+            })
+        );
+        assert!(FirstDate::nth_weekday_of_month(2025, 5, Weekday::Monday, 0).is_err());
+        assert!(FirstDate::nth_weekday_of_month(2025, 5, Weekday::Monday, -6).is_err());
+        assert!(FirstDate::nth_weekday_of_month(2025, 13, Weekday::Monday, 1).is_err());
+    }
+
+    #[test]
+    fn date_string_bc_positive() {
+        let sut = FirstDate::from_era_ymd(Era::Bc, 44, 3, 15).unwrap();
+        assert!(sut.date_string(&DateStyle::US).starts_with("03/15/44 BC "));
+        assert_eq!(
+            sut.date_string(&DateStyle::ISO),
+            "-0043-03-15 00:00:00 +00:00"
+        );
+
+        let sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        assert!(sut.date_string(&DateStyle::US).starts_with("05/23/2025 "));
+
+        let sut = FirstDate::from_ymd(43, 3, 15).unwrap();
+        assert!(sut.date_string(&DateStyle::US).starts_with("03/15/43 "));
+        assert!(sut.date_string(&DateStyle::ISO).starts_with("0043-03-15 "));
+        let style = DateStyle {
+            year_format: YearFormat::Iso,
+            ..DateStyle::EUROPEAN
+        };
+        assert!(sut.date_string(&style).starts_with("15.03.0043 "));
+    }
+
+    #[test]
+    fn date_string_presets_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(sut.date_string(&DateStyle::US), "05/23/2025 03:46:48 AM");
+        assert_eq!(sut.date_string(&DateStyle::EUROPEAN), "23.05.2025 03:46:48");
+        assert_eq!(
+            sut.date_string(&DateStyle::ISO),
+            "2025-05-23 03:46:48 +00:00"
+        );
+        assert_eq!(
+            sut.date_string(&DateStyle::default()),
+            "05/23/2025 03:46:48 AM"
+        );
+
+        sut.set_timezone(String::from("America/Chicago"));
+        assert_eq!(sut.date_string(&DateStyle::US), "05/22/2025 10:46:48 PM");
+        assert_eq!(
+            sut.date_string(&DateStyle::ISO),
+            "2025-05-22 22:46:48 -05:00"
+        );
+    }
+
+    #[test]
+    fn date_string_options_positive() {
+        let sut = FirstDate::from_ymd_hms_milli(2025, 1, 9, 0, 5, 3, 7).unwrap();
+        assert_eq!(sut.date_string(&DateStyle::US), "01/09/2025 12:05:03 AM");
+        assert_eq!(sut.date_string(&DateStyle::EUROPEAN), "09.01.2025 00:05:03");
+
+        let style = DateStyle {
+            order: DateOrder::Dmy,
+            date_separator: Cow::Borrowed("-"),
+            time_separator: Cow::Owned(String::from(".")),
+            zero_pad: false,
+            show_milliseconds: true,
+            ..DateStyle::US
+        };
+        assert_eq!(sut.date_string(&style), "9-1-2025 12.05.03.007 AM");
+
+        let sut = FirstDate::from_ymd_hms(2025, 12, 31, 12, 0, 0).unwrap();
+        let style = DateStyle {
+            zero_pad: false,
+            ..DateStyle::US
+        };
+        assert_eq!(sut.date_string(&style), "12/31/2025 12:00:00 PM");
+        let style = DateStyle {
+            clock: ClockFormat::TwentyFourHour,
+            ..style
+        };
+        assert_eq!(sut.date_string(&style), "12/31/2025 12:00:00");
+    }
+
+    #[test]
+    fn try_set_timezone_unknown_negative() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        let result = sut.try_set_timezone(String::from("Mars/Olympus_Mons"));
+        assert_eq!(
+            result,
+            Err(FirstDateError::UnknownTimezone(String::from(
+                "Mars/Olympus_Mons"
+            )))
+        );
+        assert_eq!(sut.hour, 3);
+        assert_eq!(sut.timezone_offset, None);
+    }
+
+    #[test]
+    fn set_timezone_twice_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        sut.set_timezone(String::from("America/Chicago"));
+        assert_eq!(sut.hour, 22);
+        assert_eq!(sut.day, 22);
+        assert_eq!(sut.is_daylight_savings, Some(true));
+        sut.set_timezone(String::from("Asia/Kolkata"));
+        assert_eq!(sut.hour, 9);
+        assert_eq!(sut.minute, 16);
+        assert_eq!(sut.day, 23);
+        assert_eq!(sut.timezone_name, "Asia/Kolkata");
+    }
+
+    #[test]
+    fn add_months_into_daylight_savings_positive() {
+        // 2025-01-15T12:00:00-06:00
+        let mut sut = FirstDate::from_millis(1736964000000);
+        sut.set_timezone(String::from("America/Chicago"));
+        assert_eq!(sut.is_daylight_savings, Some(false));
+        sut.add(6, TimeUnit::Month);
+        assert_eq!((sut.month, sut.day, sut.hour), (7, 15, 12));
+        assert_eq!(sut.timezone_offset, UtcOffset::from_hms(-5, 0, 0));
+        assert_eq!(sut.is_daylight_savings, Some(true));
+        assert_eq!(sut.to_millis(), 1752598800000);
+    }
+
+    #[test]
+    fn add_day_across_daylight_savings_transition_positive() {
+        // 2025-03-08T12:00:00-06:00, the clocks move forward on March 9th at 2 AM
+        let mut sut = FirstDate::from_millis(1741456800000);
+        sut.set_timezone(String::from("America/Chicago"));
+        sut.add(1, TimeUnit::Day);
+        assert_eq!((sut.day, sut.hour), (9, 12));
+        assert_eq!(sut.timezone_offset, UtcOffset::from_hms(-5, 0, 0));
+        assert_eq!(sut.is_daylight_savings, Some(true));
+        sut.add(-1, TimeUnit::Day);
+        assert_eq!((sut.day, sut.hour), (8, 12));
+        assert_eq!(sut.timezone_offset, UtcOffset::from_hms(-6, 0, 0));
+        assert_eq!(sut.is_daylight_savings, Some(false));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn try_set_daylight_savings_unset_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(sut.try_set_daylight_savings(false), Ok(()));
+        assert_eq!(sut.is_daylight_savings, Some(false));
+        assert_eq!(sut.hour, 3);
+    }
+
+    #[test]
+    fn from_ymd_hms_milli_positive() {
+        let sut = FirstDate::from_ymd_hms_milli(2025, 5, 23, 3, 46, 48, 447).unwrap();
+        assert_eq!(sut.to_millis(), 1747972008447);
+        assert_eq!(sut.month_name, "May");
+        assert!(!sut.is_leap_year);
+        assert_eq!(sut.timezone_offset, None);
+    }
+
+    #[test]
+    fn from_ymd_leap_day_positive() {
+        let sut = FirstDate::from_ymd(2024, 2, 29).unwrap();
+        assert_eq!(sut.day, 29);
+        assert_eq!(sut.month_name, "February");
+        assert!(sut.is_leap_year);
+        assert_eq!(sut.hour, 0);
+    }
+
+    #[test]
+    fn from_ymd_hms_negative() {
+        assert_eq!(
+            FirstDate::from_ymd(2025, 2, 29).unwrap_err(),
+            FirstDateError::InvalidField {
+                field: "day",
+                value: 29
+            }
+        );
+        assert!(FirstDate::from_ymd(2025, 0, 1).is_err());
+        assert!(FirstDate::from_ymd(2025, 4, 31).is_err());
+        assert!(FirstDate::from_ymd_hms(2025, 4, 30, 24, 0, 0).is_err());
+        assert!(FirstDate::from_ymd_hms(2025, 4, 30, 23, 60, 0).is_err());
+        assert!(FirstDate::from_ymd_hms(2025, 4, 30, 23, 59, 60).is_err());
+        assert!(FirstDate::from_ymd_hms_milli(2025, 4, 30, 23, 59, 59, 1000).is_err());
+    }
+
+    #[test]
+    fn default_positive() {
+        let sut = FirstDate::default();
+        assert_eq!(sut.day, 1);
+        assert_eq!(sut.month_name, "January");
+        assert_eq!(sut.to_millis(), 0);
+    }
+
+    #[test]
+    fn subtract_dates_positive() {
+        let start = FirstDate::from_ymd_hms(2025, 5, 23, 3, 46, 48).unwrap();
+        let end = FirstDate::from_ymd_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let duration = &end - &start;
+        assert_eq!(duration.whole_days(), 9);
+        assert_eq!(duration.whole_hours(), 224);
+        assert_eq!((start - end).whole_days(), -9);
+    }
+
+    #[test]
+    fn subtract_dates_across_timezones_positive() {
+        let start = FirstDate::from_ymd_hms(2025, 5, 23, 0, 0, 0).unwrap();
+        let mut end = start.clone();
+        end.set_timezone(String::from("Asia/Kolkata"));
+        assert!(end.duration_since(&start).is_zero());
+    }
+
+    #[test]
+    fn add_duration_positive() {
+        let start = FirstDate::from_ymd_hms(2025, 12, 31, 23, 0, 0).unwrap();
+        let end = &start + Duration::hours(2);
+        assert_eq!((end.year, end.month, end.day, end.hour), (2026, 1, 1, 1));
+
+        let back = end - Duration::days(1);
+        assert_eq!(
+            (back.year, back.month, back.day, back.hour),
+            (2025, 12, 31, 1)
+        );
+    }
+
+    #[test]
+    fn try_add_duration_overflow_negative() {
+        let mut sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        assert_eq!(
+            sut.try_add_duration(Duration::days(-1_000_000_000)),
+            Err(FirstDateError::Overflow)
+        );
+        assert_eq!(sut.year, 2025);
+    }
+
+    #[test]
+    fn add_month_end_of_month_clamp_positive() {
+        let mut sut = FirstDate::from_ymd_hms(2025, 1, 31, 10, 30, 0).unwrap();
+        sut.add(1, TimeUnit::Month);
+        assert_eq!((sut.year, sut.month, sut.day), (2025, 2, 28));
+        assert_eq!(sut.month_name, "February");
+        assert_eq!((sut.hour, sut.minute), (10, 30));
+
+        let mut sut = FirstDate::from_ymd(2024, 1, 31).unwrap();
+        sut.add(1, TimeUnit::Month);
+        assert_eq!((sut.month, sut.day), (2, 29));
+    }
+
+    #[test]
+    fn add_months_overflow_policy_positive() {
+        let mut sut = FirstDate::from_ymd(2025, 1, 31).unwrap();
+        sut.try_add_months(1, MonthOverflow::Overflow).unwrap();
+        assert_eq!((sut.month, sut.day), (3, 3));
+        assert_eq!(sut.month_name, "March");
+
+        let mut sut = FirstDate::from_ymd(2024, 1, 31).unwrap();
+        sut.try_add_months(1, MonthOverflow::Overflow).unwrap();
+        assert_eq!((sut.month, sut.day), (3, 2));
+    }
+
+    #[test]
+    fn add_months_error_policy_negative() {
+        let mut sut = FirstDate::from_ymd(2025, 3, 31).unwrap();
+        assert_eq!(
+            sut.try_add_months(1, MonthOverflow::Error),
+            Err(FirstDateError::InvalidField {
+                field: "day",
+                value: 31
+            })
+        );
+        assert_eq!((sut.month, sut.day), (3, 31));
+        assert_eq!(sut.try_add_months(2, MonthOverflow::Error), Ok(()));
+        assert_eq!((sut.month, sut.day), (5, 31));
+    }
+
+    #[test]
+    fn subtract_month_across_year_positive() {
+        let mut sut = FirstDate::from_ymd(2025, 3, 31).unwrap();
+        sut.add(-13, TimeUnit::Month);
+        assert_eq!((sut.year, sut.month, sut.day), (2024, 2, 29));
+        assert!(sut.is_leap_year);
+    }
+
+    #[test]
+    fn add_year_leap_day_positive() {
+        let mut sut = FirstDate::from_ymd(2024, 2, 29).unwrap();
+        sut.add(1, TimeUnit::Year);
+        assert_eq!((sut.year, sut.month, sut.day), (2025, 2, 28));
+        assert!(!sut.is_leap_year);
+
+        let mut sut = FirstDate::from_ymd(2024, 2, 29).unwrap();
+        sut.add(4, TimeUnit::Year);
+        assert_eq!((sut.year, sut.month, sut.day), (2028, 2, 29));
+
+        let mut sut = FirstDate::from_ymd(2024, 2, 29).unwrap();
+        sut.try_add_years(1, MonthOverflow::Overflow).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (2025, 3, 1));
+    }
+
+    #[test]
+    fn add_wide_range_positive() {
+        let mut sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        sut.add(100_000, TimeUnit::Second);
+        assert_eq!((sut.day, sut.hour, sut.minute, sut.second), (24, 3, 46, 40));
+
+        sut.add(1_000_000, TimeUnit::Millisecond);
+        assert_eq!((sut.hour, sut.minute, sut.second), (4, 3, 20));
+
+        sut.add(-1_000_000_000, TimeUnit::Second);
+        assert_eq!((sut.year, sut.month, sut.day), (1993, 9, 15));
+    }
+
+    #[test]
+    fn try_add_overflow_large_amounts_negative() {
+        let mut sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        assert_eq!(
+            sut.try_add(i64::MAX, TimeUnit::Day),
+            Err(FirstDateError::Overflow)
+        );
+        assert_eq!(
+            sut.try_add(i64::MIN, TimeUnit::Year),
+            Err(FirstDateError::Overflow)
+        );
+        assert_eq!(
+            sut.try_add(2_000_000, TimeUnit::Year),
+            Err(FirstDateError::Overflow)
+        );
+        assert_eq!((sut.year, sut.month, sut.day), (2025, 5, 23));
+    }
+
+    #[test]
+    fn saturating_add_positive() {
+        let mut sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        sut.saturating_add(i64::MAX, TimeUnit::Millisecond);
+        assert_eq!((sut.year, sut.month, sut.day), (999999, 12, 31));
+        assert_eq!(
+            (sut.hour, sut.minute, sut.second, sut.millisecond),
+            (23, 59, 59, 999)
+        );
+
+        sut.saturating_add(-3_000_000, TimeUnit::Year);
+        assert_eq!((sut.year, sut.month, sut.day, sut.hour), (-999999, 1, 1, 0));
+
+        sut.saturating_add(1, TimeUnit::Month);
+        assert_eq!((sut.year, sut.month, sut.day), (-999999, 2, 1));
+    }
+
+    #[test]
+    fn wrapping_add_positive() {
+        let mut sut = FirstDate::from_ymd_hms(999999, 12, 31, 23, 0, 0).unwrap();
+        sut.wrapping_add(2, TimeUnit::Hour);
+        assert_eq!((sut.year, sut.month, sut.day, sut.hour), (-999999, 1, 1, 1));
+
+        sut.wrapping_add(-2, TimeUnit::Hour);
+        assert_eq!(
+            (sut.year, sut.month, sut.day, sut.hour),
+            (999999, 12, 31, 23)
+        );
+
+        sut.wrapping_add(1, TimeUnit::Year);
+        assert_eq!((sut.year, sut.month, sut.day), (-999999, 12, 31));
+
+        sut.wrapping_add(-13, TimeUnit::Month);
+        assert_eq!((sut.year, sut.month, sut.day), (999999, 11, 30));
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn set_daylight_savings_true_positive() {
         let mock_millis: isize = 1747972008447;
         let mut sut = FirstDate::from_millis(mock_millis);
         dbg!(&sut);
 
-        // Set daylight savings
+        // Set daylight savings, only the flag changes
         sut.set_daylight_savings(true);
-        assert_eq!(sut.hour, 4);
+        assert_eq!(sut.is_daylight_savings, Some(true));
+        assert_eq!(sut.hour, 3);
+        assert_eq!(sut.to_millis(), mock_millis);
 
         // Revert daylight savings
         sut.set_daylight_savings(false);
+        assert_eq!(sut.is_daylight_savings, Some(false));
         assert_eq!(sut.hour, 3);
     }
 }
@@ -0,0 +1,525 @@
+use crate::Weekday;
+
+/// The names and default patterns of a language, used by `FirstDate::format_with_locale` and `FirstDate::parse_with_locale`. The data is part of the crate, see `Locale::ALL` for the available locales.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// The ISO 639-1 language code, for example "de".
+    pub code: &'static str,
+    /// January to December.
+    pub months: [&'static str; 12],
+    pub month_abbreviations: [&'static str; 12],
+    /// Monday to Sunday.
+    pub weekdays: [&'static str; 7],
+    pub weekday_abbreviations: [&'static str; 7],
+    /// The markers of the 12-hour clock, for `%p`.
+    pub am: &'static str,
+    pub pm: &'static str,
+    /// The usual short date, for example "%d.%m.%Y".
+    pub date_pattern: &'static str,
+    /// The usual time, for example "%H:%M:%S".
+    pub time_pattern: &'static str,
+    /// The usual date and time with the names of the weekday and the month, used by `FirstDate::to_locale_string`.
+    pub date_time_pattern: &'static str,
+}
+
+impl Locale {
+    pub const EN: Locale = Locale {
+        code: "en",
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        month_abbreviations: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        weekdays: [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        weekday_abbreviations: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        am: "AM",
+        pm: "PM",
+        date_pattern: "%m/%d/%Y",
+        time_pattern: "%I:%M:%S %p",
+        date_time_pattern: "%A, %B %-d, %Y %I:%M:%S %p",
+    };
+
+    pub const DE: Locale = Locale {
+        code: "de",
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        month_abbreviations: [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        weekdays: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        weekday_abbreviations: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        am: "AM",
+        pm: "PM",
+        date_pattern: "%d.%m.%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A, %-d. %B %Y %H:%M:%S",
+    };
+
+    pub const FR: Locale = Locale {
+        code: "fr",
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        month_abbreviations: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        weekday_abbreviations: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        am: "AM",
+        pm: "PM",
+        date_pattern: "%d/%m/%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A %-d %B %Y %H:%M:%S",
+    };
+
+    pub const ES: Locale = Locale {
+        code: "es",
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        month_abbreviations: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekdays: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        weekday_abbreviations: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        am: "a. m.",
+        pm: "p. m.",
+        date_pattern: "%d/%m/%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A, %-d de %B de %Y %H:%M:%S",
+    };
+
+    pub const PT: Locale = Locale {
+        code: "pt",
+        months: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        month_abbreviations: [
+            "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+        ],
+        weekdays: [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        weekday_abbreviations: ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+        am: "AM",
+        pm: "PM",
+        date_pattern: "%d/%m/%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A, %-d de %B de %Y %H:%M:%S",
+    };
+
+    pub const IT: Locale = Locale {
+        code: "it",
+        months: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        month_abbreviations: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        weekdays: [
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+            "domenica",
+        ],
+        weekday_abbreviations: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+        am: "AM",
+        pm: "PM",
+        date_pattern: "%d/%m/%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A %-d %B %Y %H:%M:%S",
+    };
+
+    pub const NL: Locale = Locale {
+        code: "nl",
+        months: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        month_abbreviations: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        weekdays: [
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+            "zondag",
+        ],
+        weekday_abbreviations: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        am: "a.m.",
+        pm: "p.m.",
+        date_pattern: "%d-%m-%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A %-d %B %Y %H:%M:%S",
+    };
+
+    pub const SV: Locale = Locale {
+        code: "sv",
+        months: [
+            "januari",
+            "februari",
+            "mars",
+            "april",
+            "maj",
+            "juni",
+            "juli",
+            "augusti",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        month_abbreviations: [
+            "jan", "feb", "mars", "apr", "maj", "juni", "juli", "aug", "sep", "okt", "nov", "dec",
+        ],
+        weekdays: [
+            "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag", "söndag",
+        ],
+        weekday_abbreviations: ["mån", "tis", "ons", "tors", "fre", "lör", "sön"],
+        am: "fm",
+        pm: "em",
+        date_pattern: "%Y-%m-%d",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A %-d %B %Y %H:%M:%S",
+    };
+
+    pub const PL: Locale = Locale {
+        code: "pl",
+        months: [
+            "styczeń",
+            "luty",
+            "marzec",
+            "kwiecień",
+            "maj",
+            "czerwiec",
+            "lipiec",
+            "sierpień",
+            "wrzesień",
+            "październik",
+            "listopad",
+            "grudzień",
+        ],
+        month_abbreviations: [
+            "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+        ],
+        weekdays: [
+            "poniedziałek",
+            "wtorek",
+            "środa",
+            "czwartek",
+            "piątek",
+            "sobota",
+            "niedziela",
+        ],
+        weekday_abbreviations: ["pon", "wt", "śr", "czw", "pt", "sob", "niedz"],
+        am: "AM",
+        pm: "PM",
+        date_pattern: "%d.%m.%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A, %d.%m.%Y %H:%M:%S",
+    };
+
+    pub const JA: Locale = Locale {
+        code: "ja",
+        months: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        month_abbreviations: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        weekday_abbreviations: ["月", "火", "水", "木", "金", "土", "日"],
+        am: "午前",
+        pm: "午後",
+        date_pattern: "%Y/%m/%d",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%Y年%-m月%-d日 %A %H:%M:%S",
+    };
+
+    pub const ZH: Locale = Locale {
+        code: "zh",
+        months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        month_abbreviations: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+            "星期日",
+        ],
+        weekday_abbreviations: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+        am: "上午",
+        pm: "下午",
+        date_pattern: "%Y/%m/%d",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%Y年%-m月%-d日 %A %H:%M:%S",
+    };
+
+    pub const RU: Locale = Locale {
+        code: "ru",
+        months: [
+            "январь",
+            "февраль",
+            "март",
+            "апрель",
+            "май",
+            "июнь",
+            "июль",
+            "август",
+            "сентябрь",
+            "октябрь",
+            "ноябрь",
+            "декабрь",
+        ],
+        month_abbreviations: [
+            "янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек",
+        ],
+        weekdays: [
+            "понедельник",
+            "вторник",
+            "среда",
+            "четверг",
+            "пятница",
+            "суббота",
+            "воскресенье",
+        ],
+        weekday_abbreviations: ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+        am: "AM",
+        pm: "PM",
+        date_pattern: "%d.%m.%Y",
+        time_pattern: "%H:%M:%S",
+        date_time_pattern: "%A, %d.%m.%Y %H:%M:%S",
+    };
+
+    /// All the locales of the crate.
+    pub const ALL: [&'static Locale; 12] = [
+        &Locale::EN,
+        &Locale::DE,
+        &Locale::FR,
+        &Locale::ES,
+        &Locale::PT,
+        &Locale::IT,
+        &Locale::NL,
+        &Locale::SV,
+        &Locale::PL,
+        &Locale::JA,
+        &Locale::ZH,
+        &Locale::RU,
+    ];
+
+    /// Finds a locale by its language code, ignoring case and any region ("de", "de-AT" and "de_DE" are all German).
+    pub fn from_code(code: &str) -> Option<&'static Locale> {
+        let language = code.split(['-', '_']).next().unwrap_or_default();
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.code.eq_ignore_ascii_case(language))
+    }
+
+    /// The full name of the month, from 1 (January) to 12.
+    pub fn month_name(&self, month: u8) -> &'static str {
+        self.months[(month as usize).clamp(1, 12) - 1]
+    }
+
+    /// The abbreviated name of the month, from 1 (January) to 12.
+    pub fn month_abbreviation(&self, month: u8) -> &'static str {
+        self.month_abbreviations[(month as usize).clamp(1, 12) - 1]
+    }
+
+    pub fn weekday_name(&self, weekday: Weekday) -> &'static str {
+        self.weekdays[weekday.number_from_monday() as usize - 1]
+    }
+
+    pub fn weekday_abbreviation(&self, weekday: Weekday) -> &'static str {
+        self.weekday_abbreviations[weekday.number_from_monday() as usize - 1]
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date_utilities::MONTHS;
+
+    #[test]
+    fn from_code_positive() {
+        assert_eq!(Locale::from_code("de"), Some(&Locale::DE));
+        assert_eq!(Locale::from_code("de-AT"), Some(&Locale::DE));
+        assert_eq!(Locale::from_code("PT_br"), Some(&Locale::PT));
+        assert_eq!(Locale::from_code("ja"), Some(&Locale::JA));
+        for locale in Locale::ALL {
+            assert_eq!(Locale::from_code(locale.code), Some(locale));
+        }
+    }
+
+    #[test]
+    fn from_code_negative() {
+        assert_eq!(Locale::from_code(""), None);
+        assert_eq!(Locale::from_code("xx"), None);
+        assert_eq!(Locale::from_code("english"), None);
+    }
+
+    #[test]
+    fn names_positive() {
+        assert_eq!(Locale::DE.month_name(3), "März");
+        assert_eq!(Locale::FR.month_abbreviation(2), "févr.");
+        assert_eq!(Locale::RU.weekday_name(Weekday::Friday), "пятница");
+        assert_eq!(Locale::ZH.weekday_abbreviation(Weekday::Sunday), "周日");
+        for (month, (name, _)) in (1..).zip(MONTHS) {
+            assert_eq!(Locale::EN.month_name(month), name);
+        }
+        for weekday in Weekday::ALL {
+            assert_eq!(Locale::EN.weekday_name(weekday), weekday.name());
+            assert_eq!(
+                Locale::EN.weekday_abbreviation(weekday),
+                weekday.abbreviation()
+            );
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use first_date::{FirstDate, FirstDateOperations};
+use first_date::{DateStyle, FirstDate, FirstDateOperations};
 
 fn main() {
     let test = FirstDate::now();
-    println!("{}", test.date_string(false));
-    println!("{}", test.date_string(true));
+    println!("{}", test.date_string(&DateStyle::US));
+    println!("{}", test.date_string(&DateStyle::EUROPEAN));
+    println!("{}", test.date_string(&DateStyle::ISO));
 }
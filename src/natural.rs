@@ -0,0 +1,526 @@
+use crate::date_utilities::{
+    days_from_civil, days_in_month, MILLISECONDS_IN_DAY, MILLISECONDS_IN_HOUR,
+    MILLISECONDS_IN_MINUTE,
+};
+use crate::error::ParseError;
+use crate::scanner::Scanner;
+use crate::{FirstDate, FirstDateOperations, Locale, TimeUnit, Weekday};
+
+/// The units of "in 3 days" and "2 weeks ago": the name, the unit and how many of the unit it is.
+const UNITS: [(&str, TimeUnit, i64); 20] = [
+    ("seconds", TimeUnit::Second, 1),
+    ("second", TimeUnit::Second, 1),
+    ("secs", TimeUnit::Second, 1),
+    ("sec", TimeUnit::Second, 1),
+    ("minutes", TimeUnit::Minute, 1),
+    ("minute", TimeUnit::Minute, 1),
+    ("mins", TimeUnit::Minute, 1),
+    ("min", TimeUnit::Minute, 1),
+    ("hours", TimeUnit::Hour, 1),
+    ("hour", TimeUnit::Hour, 1),
+    ("hrs", TimeUnit::Hour, 1),
+    ("hr", TimeUnit::Hour, 1),
+    ("days", TimeUnit::Day, 1),
+    ("day", TimeUnit::Day, 1),
+    ("weeks", TimeUnit::Day, 7),
+    ("week", TimeUnit::Day, 7),
+    ("months", TimeUnit::Month, 1),
+    ("month", TimeUnit::Month, 1),
+    ("years", TimeUnit::Year, 1),
+    ("year", TimeUnit::Year, 1),
+];
+
+impl FirstDate {
+    /// Parses a date written by a person, such as "tomorrow", "next Tuesday at 5pm" or "3 days ago", relative to the `reference` date (usually `FirstDate::now()`). Words are english and case is ignored.
+    /// - "now", "today", "tomorrow" and "yesterday".
+    /// - "next Tuesday" and "last Tuesday" (strictly after or before the reference), "Tuesday" (the reference itself if it is a Tuesday, otherwise the next one), "next week" and "last year".
+    /// - "in 3 days", "in an hour", "2 weeks ago" and "5 minutes from now", the units are seconds to years.
+    /// - Absolute dates: "2025-05-23", "05/23/2025", "23 May 2025", "May 23rd, 2025" and "May 23" (in the year of the reference).
+    /// - A time of day before or after the date, optionally after "at": "5pm", "5:30 p.m.", "17:30", "17:30:15", "noon" and "midnight".
+    ///
+    /// Relative days keep the time of day of the reference, absolute dates start at midnight, and a time of day without a date is on the day of the reference. The result keeps the timezone of the reference.
+    pub fn parse_natural(input: &str, reference: &FirstDate) -> Result<FirstDate, ParseError> {
+        let mut scanner = Scanner::new(input);
+        scanner.skip_whitespace();
+        if eat_word(&mut scanner, "now") {
+            scanner.skip_whitespace();
+            scanner.finish()?;
+            return Ok(reference.clone());
+        }
+
+        let mut date: Option<FirstDate> = None;
+        let mut time: Option<(i64, usize)> = None;
+        while date.is_none() || time.is_none() {
+            scanner.skip_whitespace();
+            if scanner.is_at_end() && (date.is_some() || time.is_some()) {
+                break;
+            }
+            if eat_word(&mut scanner, "at") || eat_word(&mut scanner, "on") {
+                scanner.skip_whitespace();
+            }
+            if time.is_none() {
+                let start = scanner.position();
+                if let Some(millis) = scan_time_of_day(&mut scanner)? {
+                    time = Some((millis, start));
+                    continue;
+                }
+            }
+            if date.is_some() {
+                break;
+            }
+            date = Some(scan_date(&mut scanner, reference)?);
+        }
+        scanner.skip_whitespace();
+        scanner.finish()?;
+
+        let mut date = date.unwrap_or_else(|| reference.clone());
+        if let Some((millis, position)) = time {
+            let days = days_from_civil(date.year as i64, date.month, date.day);
+            date.try_set_local_millis(days * MILLISECONDS_IN_DAY + millis)
+                .map_err(|_| ParseError::invalid_field(position, "time", millis))?;
+        }
+        Ok(date)
+    }
+}
+
+/// Skips the word if the input continues with it (ignoring case) and the word is not just the beginning of a longer one.
+fn eat_word(scanner: &mut Scanner, word: &str) -> bool {
+    let mut attempt = scanner.clone();
+    if attempt.eat_ignore_case(word) && !attempt.peek().is_some_and(char::is_alphanumeric) {
+        *scanner = attempt;
+        return true;
+    }
+    false
+}
+
+fn scan_weekday(scanner: &mut Scanner) -> Option<Weekday> {
+    Weekday::ALL
+        .into_iter()
+        .find(|weekday| eat_word(scanner, weekday.name()))
+        .or_else(|| {
+            Weekday::ALL
+                .into_iter()
+                .find(|weekday| eat_word(scanner, weekday.abbreviation()))
+        })
+}
+
+/// Reads an english month name or abbreviation (and "Sept") and returns the month number.
+fn scan_month(scanner: &mut Scanner) -> Option<u8> {
+    let locale = Locale::EN;
+    let names = locale.months.into_iter().zip(1..);
+    let abbreviations = locale.month_abbreviations.into_iter().zip(1..);
+    names
+        .chain(abbreviations)
+        .chain([("Sept", 9)])
+        .find(|(name, _)| eat_word(scanner, name))
+        .map(|(_, month)| month)
+}
+
+fn scan_unit(scanner: &mut Scanner) -> Option<(TimeUnit, i64)> {
+    UNITS
+        .iter()
+        .find(|(name, _, _)| eat_word(scanner, name))
+        .map(|(_, unit, multiplier)| (*unit, *multiplier))
+}
+
+/// Reads a number or "a"/"an" (one).
+fn scan_amount(scanner: &mut Scanner) -> Result<i64, ParseError> {
+    if eat_word(scanner, "a") || eat_word(scanner, "an") {
+        return Ok(1);
+    }
+    scanner.number(1, 9, "amount")
+}
+
+/// Reads the "st", "nd", "rd" or "th" of "23rd" if there is one.
+fn skip_ordinal_suffix(scanner: &mut Scanner) {
+    let _ = ["st", "nd", "rd", "th"]
+        .iter()
+        .any(|suffix| eat_word(scanner, suffix));
+}
+
+/// The date moved by an amount of a unit (weeks are 7 days). `position` is where the amount starts, for the error.
+fn shifted(
+    reference: &FirstDate,
+    amount: i64,
+    (unit, multiplier): (TimeUnit, i64),
+    position: usize,
+) -> Result<FirstDate, ParseError> {
+    let mut date = reference.clone();
+    amount
+        .checked_mul(multiplier)
+        .and_then(|amount| date.try_add(amount, unit).ok())
+        .ok_or_else(|| ParseError::invalid_field(position, "amount", amount.abs()))?;
+    Ok(date)
+}
+
+/// Reads a time of day and returns it in milliseconds since midnight. Returns `None` (and reads nothing) if the input does not continue with a time, for example "5 days".
+fn scan_time_of_day(scanner: &mut Scanner) -> Result<Option<i64>, ParseError> {
+    if eat_word(scanner, "noon") {
+        return Ok(Some(12 * MILLISECONDS_IN_HOUR));
+    }
+    if eat_word(scanner, "midnight") {
+        return Ok(Some(0));
+    }
+    if !scanner.peek_is_digit() || scanner.digit_count() > 2 {
+        return Ok(None);
+    }
+
+    let mut attempt = scanner.clone();
+    let start = attempt.position();
+    let mut hour = attempt.number(1, 2, "hour")?;
+    let (mut minute, mut second) = (0, 0);
+    let has_minutes = attempt.eat(':');
+    if has_minutes {
+        minute = attempt.field(2, "minute", 0, 59)?;
+        if attempt.eat(':') {
+            second = attempt.field(2, "second", 0, 59)?;
+        }
+    }
+
+    let mut meridiem = attempt.clone();
+    meridiem.skip_whitespace();
+    let is_pm = if eat_word(&mut meridiem, "am") || eat_word(&mut meridiem, "a.m.") {
+        Some(false)
+    } else if eat_word(&mut meridiem, "pm") || eat_word(&mut meridiem, "p.m.") {
+        Some(true)
+    } else {
+        None
+    };
+    match is_pm {
+        Some(is_pm) => {
+            if !(1..=12).contains(&hour) {
+                return Err(ParseError::invalid_field(start, "hour", hour));
+            }
+            hour = hour % 12 + if is_pm { 12 } else { 0 };
+            attempt = meridiem;
+        }
+        None if has_minutes => {
+            if hour > 23 {
+                return Err(ParseError::invalid_field(start, "hour", hour));
+            }
+        }
+        None => return Ok(None),
+    }
+
+    *scanner = attempt;
+    Ok(Some(
+        hour * MILLISECONDS_IN_HOUR + minute * MILLISECONDS_IN_MINUTE + second * 1000,
+    ))
+}
+
+/// Reads a relative or absolute date.
+fn scan_date(scanner: &mut Scanner, reference: &FirstDate) -> Result<FirstDate, ParseError> {
+    let start = scanner.position();
+    if eat_word(scanner, "today") {
+        return Ok(reference.clone());
+    }
+    if eat_word(scanner, "tomorrow") {
+        return shifted(reference, 1, (TimeUnit::Day, 1), start);
+    }
+    if eat_word(scanner, "yesterday") {
+        return shifted(reference, -1, (TimeUnit::Day, 1), start);
+    }
+
+    let direction = if eat_word(scanner, "next") {
+        Some(1)
+    } else if eat_word(scanner, "last") {
+        Some(-1)
+    } else {
+        None
+    };
+    if let Some(direction) = direction {
+        scanner.skip_whitespace();
+        if let Some(weekday) = scan_weekday(scanner) {
+            return Ok(if direction == 1 {
+                reference.next_weekday(weekday)
+            } else {
+                reference.previous_weekday(weekday)
+            });
+        }
+        let unit = scan_unit(scanner).ok_or_else(|| scanner.unexpected("weekday or unit"))?;
+        return shifted(reference, direction, unit, start);
+    }
+
+    if let Some(weekday) = scan_weekday(scanner) {
+        if reference.weekday() == weekday {
+            return Ok(reference.clone());
+        }
+        return Ok(reference.next_weekday(weekday));
+    }
+
+    if eat_word(scanner, "in") {
+        scanner.skip_whitespace();
+        let amount_start = scanner.position();
+        let amount = scan_amount(scanner)?;
+        scanner.skip_whitespace();
+        let unit = scan_unit(scanner).ok_or_else(|| scanner.unexpected("unit"))?;
+        return shifted(reference, amount, unit, amount_start);
+    }
+
+    // "3 days ago" and "3 days from now", otherwise an absolute date like "3 May"
+    let mut attempt = scanner.clone();
+    if let Ok(amount) = scan_amount(&mut attempt) {
+        attempt.skip_whitespace();
+        if let Some(unit) = scan_unit(&mut attempt) {
+            attempt.skip_whitespace();
+            let direction = if eat_word(&mut attempt, "ago") {
+                -1
+            } else if eat_word(&mut attempt, "from") {
+                attempt.skip_whitespace();
+                if !eat_word(&mut attempt, "now") {
+                    return Err(attempt.unexpected("'now'"));
+                }
+                1
+            } else {
+                return Err(attempt.unexpected("'ago' or 'from now'"));
+            };
+            *scanner = attempt;
+            return shifted(reference, direction * amount, unit, start);
+        }
+    }
+
+    scan_absolute_date(scanner, reference)
+}
+
+/// Reads "2025-05-23", "05/23/2025", "05/23", "23 May 2025", "23rd of May", "May 23rd, 2025" or "May 23", and returns the date at midnight.
+fn scan_absolute_date(
+    scanner: &mut Scanner,
+    reference: &FirstDate,
+) -> Result<FirstDate, ParseError> {
+    let mut year = reference.year as i64;
+    let year_start = scanner.position();
+    let (month, day, day_start);
+
+    if scanner.digit_count() == 4 {
+        year = scanner.number(4, 4, "year")?;
+        scanner.expect('-', "'-'")?;
+        month = scanner.field_between(1, 2, "month", 1, 12)? as u8;
+        scanner.expect('-', "'-'")?;
+        day_start = scanner.position();
+        day = scanner.field_between(1, 2, "day", 1, 31)?;
+    } else if scanner.peek_is_digit() {
+        let first_start = scanner.position();
+        let first = scanner.number(1, 2, "day")?;
+        if scanner.eat('/') {
+            if !(1..=12).contains(&first) {
+                return Err(ParseError::invalid_field(first_start, "month", first));
+            }
+            month = first as u8;
+            day_start = scanner.position();
+            day = scanner.field_between(1, 2, "day", 1, 31)?;
+            if scanner.eat('/') {
+                year = scanner.number(4, 4, "year")?;
+            }
+        } else {
+            day_start = first_start;
+            day = first;
+            skip_ordinal_suffix(scanner);
+            scanner.skip_whitespace();
+            if eat_word(scanner, "of") {
+                scanner.skip_whitespace();
+            }
+            month = scan_month(scanner).ok_or_else(|| scanner.unexpected("month"))?;
+            year = scan_optional_year(scanner).unwrap_or(year);
+        }
+    } else if let Some(found) = scan_month(scanner) {
+        month = found;
+        scanner.skip_whitespace();
+        day_start = scanner.position();
+        day = scanner.field_between(1, 2, "day", 1, 31)?;
+        skip_ordinal_suffix(scanner);
+        year = scan_optional_year(scanner).unwrap_or(year);
+    } else {
+        return Err(scanner.unexpected("date"));
+    }
+
+    if day > days_in_month(year, month) as i64 {
+        return Err(ParseError::invalid_field(day_start, "day", day));
+    }
+    let mut date = reference.clone();
+    date.try_set_local_millis(days_from_civil(year, month, day as u8) * MILLISECONDS_IN_DAY)
+        .map_err(|_| ParseError::invalid_field(year_start, "year", year))?;
+    Ok(date)
+}
+
+/// Reads ", 2025" or " 2025" after a day and a month. Only four digit years are read so that "May 23 5pm" is not the year 5.
+fn scan_optional_year(scanner: &mut Scanner) -> Option<i64> {
+    let mut attempt = scanner.clone();
+    attempt.skip_whitespace();
+    if attempt.eat(',') {
+        attempt.skip_whitespace();
+    }
+    if attempt.digit_count() != 4 {
+        return None;
+    }
+    let year = attempt.number(4, 4, "year").ok()?;
+    *scanner = attempt;
+    Some(year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+
+    fn reference() -> FirstDate {
+        // Friday May 23rd 2025 03:46:48.447 UTC
+        FirstDate::from_millis(1747972008447)
+    }
+
+    fn at(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+    ) -> FirstDate {
+        FirstDate::from_ymd_hms_milli(year, month, day, hour, minute, second, millisecond).unwrap()
+    }
+
+    #[test]
+    fn parse_natural_relative_positive() {
+        let cases = [
+            ("now", at(2025, 5, 23, 3, 46, 48, 447)),
+            ("today", at(2025, 5, 23, 3, 46, 48, 447)),
+            ("Tomorrow", at(2025, 5, 24, 3, 46, 48, 447)),
+            ("yesterday", at(2025, 5, 22, 3, 46, 48, 447)),
+            ("next Tuesday", at(2025, 5, 27, 3, 46, 48, 447)),
+            ("next friday", at(2025, 5, 30, 3, 46, 48, 447)),
+            ("last fri", at(2025, 5, 16, 3, 46, 48, 447)),
+            ("friday", at(2025, 5, 23, 3, 46, 48, 447)),
+            ("on Monday", at(2025, 5, 26, 3, 46, 48, 447)),
+            ("next week", at(2025, 5, 30, 3, 46, 48, 447)),
+            ("next month", at(2025, 6, 23, 3, 46, 48, 447)),
+            ("last year", at(2024, 5, 23, 3, 46, 48, 447)),
+            ("in 3 days", at(2025, 5, 26, 3, 46, 48, 447)),
+            ("in an hour", at(2025, 5, 23, 4, 46, 48, 447)),
+            ("in 90 minutes", at(2025, 5, 23, 5, 16, 48, 447)),
+            ("2 hours ago", at(2025, 5, 23, 1, 46, 48, 447)),
+            ("a week ago", at(2025, 5, 16, 3, 46, 48, 447)),
+            ("3 months from now", at(2025, 8, 23, 3, 46, 48, 447)),
+            ("  10 SECONDS AGO ", at(2025, 5, 23, 3, 46, 38, 447)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                FirstDate::parse_natural(input, &reference()),
+                Ok(expected),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn parse_natural_time_positive() {
+        let cases = [
+            ("5pm", at(2025, 5, 23, 17, 0, 0, 0)),
+            ("at 5:30 p.m.", at(2025, 5, 23, 17, 30, 0, 0)),
+            ("17:30:15", at(2025, 5, 23, 17, 30, 15, 0)),
+            ("12am", at(2025, 5, 23, 0, 0, 0, 0)),
+            ("12 PM", at(2025, 5, 23, 12, 0, 0, 0)),
+            ("noon", at(2025, 5, 23, 12, 0, 0, 0)),
+            ("tomorrow at 5pm", at(2025, 5, 24, 17, 0, 0, 0)),
+            ("5pm tomorrow", at(2025, 5, 24, 17, 0, 0, 0)),
+            ("next Tuesday at 5pm", at(2025, 5, 27, 17, 0, 0, 0)),
+            ("yesterday at midnight", at(2025, 5, 22, 0, 0, 0, 0)),
+            ("in 2 days at 9:15", at(2025, 5, 25, 9, 15, 0, 0)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                FirstDate::parse_natural(input, &reference()),
+                Ok(expected),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn parse_natural_absolute_positive() {
+        let cases = [
+            ("2025-12-25", at(2025, 12, 25, 0, 0, 0, 0)),
+            ("12/25/2025", at(2025, 12, 25, 0, 0, 0, 0)),
+            ("12/25", at(2025, 12, 25, 0, 0, 0, 0)),
+            ("25 December 2026", at(2026, 12, 25, 0, 0, 0, 0)),
+            ("25th of Dec", at(2025, 12, 25, 0, 0, 0, 0)),
+            (
+                "December 25th, 2025 at 9:30am",
+                at(2025, 12, 25, 9, 30, 0, 0),
+            ),
+            ("Sept 1", at(2025, 9, 1, 0, 0, 0, 0)),
+            ("May 23 5pm", at(2025, 5, 23, 17, 0, 0, 0)),
+            ("Feb 29 2024", at(2024, 2, 29, 0, 0, 0, 0)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                FirstDate::parse_natural(input, &reference()),
+                Ok(expected),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn parse_natural_keeps_timezone_positive() {
+        let mut reference = reference();
+        reference.set_timezone(String::from("America/Chicago"));
+        let sut = FirstDate::parse_natural("tomorrow at 5pm", &reference).unwrap();
+        assert_eq!(sut.to_rfc3339(), "2025-05-23T17:00:00.000-05:00");
+        assert_eq!(sut.timezone_name, "America/Chicago");
+    }
+
+    #[test]
+    fn parse_natural_negative() {
+        assert_eq!(
+            FirstDate::parse_natural("", &reference()),
+            Err(ParseError::new(
+                0,
+                ParseErrorKind::UnexpectedEnd { expected: "date" }
+            ))
+        );
+        assert_eq!(
+            FirstDate::parse_natural("next blursday", &reference()),
+            Err(ParseError::new(
+                5,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'b',
+                    expected: "weekday or unit"
+                }
+            ))
+        );
+        assert_eq!(
+            FirstDate::parse_natural("13/01/2025", &reference()),
+            Err(ParseError::invalid_field(0, "month", 13))
+        );
+        assert_eq!(
+            FirstDate::parse_natural("February 30", &reference()),
+            Err(ParseError::invalid_field(9, "day", 30))
+        );
+        assert_eq!(
+            FirstDate::parse_natural("13pm", &reference()),
+            Err(ParseError::invalid_field(0, "hour", 13))
+        );
+        assert_eq!(
+            FirstDate::parse_natural("in 999999999 years", &reference()),
+            Err(ParseError::invalid_field(3, "amount", 999999999))
+        );
+        for input in [
+            "someday",
+            "in 3 fortnights",
+            "3 days",
+            "25:00",
+            "tomorrow tomorrow",
+            "5pm 6pm",
+            "now please",
+        ] {
+            assert!(
+                FirstDate::parse_natural(input, &reference()).is_err(),
+                "{}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::date_utilities::{
+    check_field, civil_from_days, days_from_civil, format_iso_year, is_leap_year,
+};
+use crate::error::ParseError;
+use crate::iso8601::{scan_iso_year, scan_ordinal_date};
+use crate::scanner::Scanner;
+use crate::{FirstDate, FirstDateError, FirstDateOperations};
+
+impl FirstDate {
+    /// Creates a new instance at midnight of the given day of the year, 1 is January 1st. Returns `FirstDateError::InvalidField` if the day is not between 1 and 365 (366 in leap years).
+    pub fn from_ordinal(year: i32, day_of_year: u16) -> Result<FirstDate, FirstDateError> {
+        let days_in_year = if is_leap_year(year as i64) { 366 } else { 365 };
+        check_field("day of year", day_of_year as i64, 1, days_in_year)?;
+        let days = days_from_civil(year as i64, 1, 1) + day_of_year as i64 - 1;
+        let (_, month, day) = civil_from_days(days);
+        FirstDate::from_ymd(year, month, day)
+    }
+
+    /// The day of the year of the ordinal date, the same as `day_of_year()`.
+    pub fn ordinal(&self) -> u16 {
+        self.day_of_year()
+    }
+
+    /// Formats the date as an ISO 8601 ordinal date `YYYY-DDD`, for example "2025-143" for May 23rd 2025.
+    pub fn ordinal_date_string(&self) -> String {
+        format!(
+            "{}-{:03}",
+            format_iso_year(self.year as i64),
+            self.ordinal()
+        )
+    }
+
+    /// Parses an ISO 8601 ordinal date `YYYY-DDD` (the format of `ordinal_date_string`) into midnight of that day.
+    pub fn parse_ordinal_date(value: &str) -> Result<FirstDate, ParseError> {
+        let mut scanner = Scanner::new(value);
+        let year = scan_iso_year(&mut scanner)?;
+        scanner.expect('-', "'-'")?;
+        let date = scan_ordinal_date(&mut scanner, year)?;
+        scanner.finish()?;
+        Ok(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_ordinal_positive() {
+        let sut = FirstDate::from_ordinal(2025, 143).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (2025, 5, 23));
+        assert_eq!(sut.ordinal(), 143);
+
+        let sut = FirstDate::from_ordinal(2024, 60).unwrap();
+        assert_eq!((sut.month, sut.day), (2, 29));
+        let sut = FirstDate::from_ordinal(2025, 60).unwrap();
+        assert_eq!((sut.month, sut.day), (3, 1));
+
+        let sut = FirstDate::from_ordinal(2024, 366).unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (2024, 12, 31));
+    }
+
+    #[test]
+    fn from_ordinal_negative() {
+        assert_eq!(
+            FirstDate::from_ordinal(2025, 366).err(),
+            Some(FirstDateError::InvalidField {
+                field: "day of year",
+                value: 366
+            })
+        );
+        assert!(FirstDate::from_ordinal(2024, 0).is_err());
+        assert!(FirstDate::from_ordinal(2024, 367).is_err());
+    }
+
+    #[test]
+    fn ordinal_date_round_trip_positive() {
+        let mut sut = FirstDate::from_ymd(2023, 12, 1).unwrap();
+        for _ in 0..500 {
+            let parsed = FirstDate::parse_ordinal_date(&sut.ordinal_date_string()).unwrap();
+            assert_eq!(parsed, sut);
+            sut.add(1, crate::TimeUnit::Day);
+        }
+
+        assert_eq!(
+            FirstDate::from_ymd(2025, 1, 9)
+                .unwrap()
+                .ordinal_date_string(),
+            "2025-009"
+        );
+        let sut = FirstDate::parse_ordinal_date("-0043-074").unwrap();
+        assert_eq!((sut.year, sut.month, sut.day), (-43, 3, 15));
+    }
+
+    #[test]
+    fn parse_ordinal_date_negative() {
+        for value in [
+            "2025-1",
+            "2025-0143",
+            "2025143",
+            "2025-366",
+            "2025-000",
+            "25-143",
+            "2025-1x3",
+        ] {
+            assert!(
+                FirstDate::parse_ordinal_date(value).is_err(),
+                "{} should not parse",
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,453 @@
+use crate::date_utilities::{
+    MonthOverflow, MILLISECONDS_IN_DAY, MILLISECONDS_IN_HOUR, MILLISECONDS_IN_MINUTE,
+};
+use crate::error::{FirstDateError, ParseError};
+use crate::scanner::Scanner;
+use crate::FirstDate;
+use std::fmt;
+
+/// An amount of calendar time like the ISO 8601 duration "P1Y2M10DT2H30M". Unlike `Duration`, the length of its years, months and days depends on the date it is added to (see `FirstDate::try_add_period`).
+/// Each field can be negative, `Period::parse` reads "-P1D" and "P-1D" as minus one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Period {
+    pub years: i64,
+    pub months: i64,
+    /// Weeks are stored as 7 days.
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    /// The fraction of the seconds, "PT1.5S" is 1 second and 500 milliseconds.
+    pub milliseconds: i64,
+}
+
+impl Period {
+    pub const ZERO: Period = Period {
+        years: 0,
+        months: 0,
+        days: 0,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+        milliseconds: 0,
+    };
+
+    pub fn is_zero(&self) -> bool {
+        *self == Period::ZERO
+    }
+
+    /// The period with every field negated, to subtract it. Returns `None` if a field is `i64::MIN`, which has no positive counterpart.
+    pub fn checked_neg(&self) -> Option<Period> {
+        Some(Period {
+            years: self.years.checked_neg()?,
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            hours: self.hours.checked_neg()?,
+            minutes: self.minutes.checked_neg()?,
+            seconds: self.seconds.checked_neg()?,
+            milliseconds: self.milliseconds.checked_neg()?,
+        })
+    }
+
+    /// Parses an ISO 8601 duration, "PnYnMnDTnHnMnS" or "PnW", for example "P1Y2M10DT2H30M", "PT1.5S" or "P2W".
+    /// - The fields can be left out but must be in order, and the "T" comes before the hours, minutes and seconds. The seconds can have a fraction (with "." or ","), digits past the milliseconds are dropped.
+    /// - A "-" before the "P" negates the period and a "-" before a number negates the field.
+    /// - Weeks are read as 7 days, so "P2W" is the same as "P14D".
+    pub fn parse(value: &str) -> Result<Period, ParseError> {
+        let mut scanner = Scanner::new(value);
+        // the sign before the "P" is applied to every field while reading it
+        let period_sign = if scanner.eat_any(&['+', '-']) == Some('-') {
+            -1
+        } else {
+            1
+        };
+        scanner.expect('P', "'P'")?;
+
+        let mut period = Period::ZERO;
+        let mut is_time = false;
+        let mut previous_designator = None;
+        loop {
+            if !is_time && scanner.eat('T') {
+                is_time = true;
+            }
+            let start = scanner.position();
+            let sign = if scanner.eat('-') { -1 } else { 1 } * period_sign;
+            let value = sign * scanner.number(1, 18, "number")?;
+            let fraction = match scanner.eat_any(&['.', ',']) {
+                Some(_) => Some(scanner.fraction_millis()? as i64 * sign),
+                None => None,
+            };
+
+            // the order of the designators, weeks are between the months and the days
+            let before_designator = scanner.clone();
+            let designator = match (is_time, scanner.advance()) {
+                (false, Some('Y')) => 0,
+                (false, Some('M')) => 1,
+                (false, Some('W')) => 2,
+                (false, Some('D')) => 3,
+                (true, Some('H')) => 4,
+                (true, Some('M')) => 5,
+                (true, Some('S')) => 6,
+                _ => 7,
+            };
+            let is_in_order =
+                !matches!(previous_designator, Some(previous) if designator <= previous);
+            if designator == 7 || !is_in_order || (fraction.is_some() && designator != 6) {
+                return Err(before_designator.unexpected("designator"));
+            }
+            previous_designator = Some(designator);
+
+            match designator {
+                0 => period.years = value,
+                1 => period.months = value,
+                2 => {
+                    period.days = value
+                        .checked_mul(7)
+                        .ok_or_else(|| ParseError::invalid_field(start, "weeks", value))?;
+                }
+                3 => period.days = value,
+                4 => period.hours = value,
+                5 => period.minutes = value,
+                _ => {
+                    period.seconds = value;
+                    period.milliseconds = fraction.unwrap_or(0);
+                }
+            }
+
+            if scanner.is_at_end() {
+                break;
+            }
+        }
+
+        Ok(period)
+    }
+}
+
+/// Writes the period in the ISO 8601 format, for example "P1Y2M10DT2H30M" or "PT1.5S". Fields that are 0 are left out and an empty period is "PT0S". If every field is negative or 0 the period is written with a leading "-", otherwise each negative field has its own sign ("P1Y-2M").
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "PT0S");
+        }
+        let total_millis = self.seconds as i128 * 1000 + self.milliseconds as i128;
+        let fields = [
+            self.years as i128,
+            self.months as i128,
+            self.days as i128,
+            self.hours as i128,
+            self.minutes as i128,
+            total_millis,
+        ];
+        let is_negative = fields.iter().all(|field| *field <= 0);
+        let sign = if is_negative { -1 } else { 1 };
+        if is_negative {
+            write!(f, "-")?;
+        }
+
+        write!(f, "P")?;
+        for (field, designator) in fields[..3].iter().zip(['Y', 'M', 'D']) {
+            if *field != 0 {
+                write!(f, "{}{}", field * sign, designator)?;
+            }
+        }
+        if fields[3..].iter().all(|field| *field == 0) {
+            return Ok(());
+        }
+        write!(f, "T")?;
+        for (field, designator) in fields[3..5].iter().zip(['H', 'M']) {
+            if *field != 0 {
+                write!(f, "{}{}", field * sign, designator)?;
+            }
+        }
+        if total_millis != 0 {
+            let millis = total_millis * sign;
+            let seconds_sign = if millis < 0 { "-" } else { "" };
+            let (seconds, fraction) = (millis.abs() / 1000, millis.abs() % 1000);
+            if fraction == 0 {
+                write!(f, "{}{}S", seconds_sign, seconds)?;
+            } else {
+                let fraction = format!("{:03}", fraction);
+                let fraction = fraction.trim_end_matches('0');
+                write!(f, "{}{}.{}S", seconds_sign, seconds, fraction)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses an ISO 8601 duration like `Period::parse`.
+impl std::str::FromStr for Period {
+    type Err = FirstDateError;
+
+    fn from_str(value: &str) -> Result<Period, FirstDateError> {
+        Ok(Period::parse(value)?)
+    }
+}
+
+impl FirstDate {
+    /// Adds a `Period`. If the result is out of the supported range the date is left unchanged.
+    pub fn add_period(&mut self, period: &Period) {
+        let _ = self.try_add_period(period);
+    }
+
+    /// Adds a `Period` in three steps, like ISO 8601 and most date libraries:
+    /// 1. the years and months together, keeping the day of the month (clamped to the end of the month like `add(n, TimeUnit::Month)`, so January 31st + "P1M" is February 28th or 29th),
+    /// 2. then the days,
+    /// 3. then the hours, minutes, seconds and milliseconds.
+    ///
+    /// Every step moves the wall clock time like `add`, and the offset and daylight savings time of the timezone at the new time are applied after it. So in a timezone "PT24H" is the same as "P1D": over a daylight savings transition the time of day stays the same while 23 or 25 hours have passed.
+    ///
+    /// Returns `FirstDateError::Overflow` (and leaves the date unchanged) if the result is out of the supported range.
+    pub fn try_add_period(&mut self, period: &Period) -> Result<(), FirstDateError> {
+        let months = period
+            .years
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(period.months))
+            .ok_or(FirstDateError::Overflow)?;
+        let day_millis = period
+            .days
+            .checked_mul(MILLISECONDS_IN_DAY)
+            .ok_or(FirstDateError::Overflow)?;
+        let time_millis = [
+            (period.hours, MILLISECONDS_IN_HOUR),
+            (period.minutes, MILLISECONDS_IN_MINUTE),
+            (period.seconds, 1000),
+            (period.milliseconds, 1),
+        ]
+        .iter()
+        .try_fold(0i64, |total, (amount, unit_millis)| {
+            amount.checked_mul(*unit_millis)?.checked_add(total)
+        })
+        .ok_or(FirstDateError::Overflow)?;
+
+        let mut date = self.clone();
+        date.try_add_calendar_months(months, MonthOverflow::Clamp)?;
+        date.try_shift_millis(day_millis)?;
+        date.try_shift_millis(time_millis)?;
+        *self = date;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+    use crate::{Duration, FirstDateOperations};
+
+    #[test]
+    fn parse_positive() {
+        let expected = Period {
+            years: 1,
+            months: 2,
+            days: 10,
+            hours: 2,
+            minutes: 30,
+            ..Period::ZERO
+        };
+        assert_eq!(Period::parse("P1Y2M10DT2H30M"), Ok(expected));
+        assert_eq!(
+            Period::parse("PT1.5S"),
+            Ok(Period {
+                seconds: 1,
+                milliseconds: 500,
+                ..Period::ZERO
+            })
+        );
+        assert_eq!(
+            Period::parse("PT0,123456S"),
+            Ok(Period {
+                milliseconds: 123,
+                ..Period::ZERO
+            })
+        );
+        assert_eq!(
+            Period::parse("P2W"),
+            Ok(Period {
+                days: 14,
+                ..Period::ZERO
+            })
+        );
+        assert_eq!(
+            Period::parse("P1M"),
+            Ok(Period {
+                months: 1,
+                ..Period::ZERO
+            })
+        );
+        assert_eq!(
+            Period::parse("PT1M"),
+            Ok(Period {
+                minutes: 1,
+                ..Period::ZERO
+            })
+        );
+        assert_eq!(
+            Period::parse("-P1Y2M10DT2H30M"),
+            Ok(expected.checked_neg().unwrap())
+        );
+        assert_eq!(
+            Period::parse("P1Y-2M"),
+            Ok(Period {
+                years: 1,
+                months: -2,
+                ..Period::ZERO
+            })
+        );
+        assert_eq!("P0D".parse::<Period>(), Ok(Period::ZERO));
+        assert_eq!(
+            Period::parse("-P-1D"),
+            Ok(Period {
+                days: 1,
+                ..Period::ZERO
+            })
+        );
+    }
+
+    #[test]
+    fn checked_neg_negative() {
+        let period = Period {
+            years: i64::MIN,
+            ..Period::ZERO
+        };
+        assert_eq!(period.checked_neg(), None);
+        let period = Period {
+            milliseconds: i64::MIN,
+            ..Period::ZERO
+        };
+        assert_eq!(period.checked_neg(), None);
+    }
+
+    #[test]
+    fn parse_negative() {
+        assert_eq!(
+            Period::parse("P1D2Y"),
+            Err(ParseError::new(
+                4,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'Y',
+                    expected: "designator"
+                }
+            ))
+        );
+        assert_eq!(
+            Period::parse("P"),
+            Err(ParseError::new(
+                1,
+                ParseErrorKind::UnexpectedEnd { expected: "number" }
+            ))
+        );
+        assert_eq!(
+            Period::parse("P1H"),
+            Err(ParseError::new(
+                2,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'H',
+                    expected: "designator"
+                }
+            ))
+        );
+        for value in [
+            "", "1Y", "PT", "P1YT", "P1.5Y", "PT1.5M", "P1Y1Y", "PT1S2M", "P1", "P1Y ",
+        ] {
+            assert!(Period::parse(value).is_err(), "{}", value);
+        }
+        assert!("P1X".parse::<Period>().is_err());
+    }
+
+    #[test]
+    fn display_positive() {
+        for value in [
+            "P1Y2M10DT2H30M",
+            "PT1.5S",
+            "PT0.007S",
+            "P14D",
+            "PT0S",
+            "-P1DT1H",
+            "-PT0.5S",
+            "P1Y-2M",
+            "PT1H-30M",
+        ] {
+            assert_eq!(Period::parse(value).unwrap().to_string(), value);
+        }
+        assert_eq!(Period::parse("P2W").unwrap().to_string(), "P14D");
+        assert_eq!(Period::parse("PT1.500S").unwrap().to_string(), "PT1.5S");
+        let mixed_seconds = Period {
+            seconds: 2,
+            milliseconds: -500,
+            ..Period::ZERO
+        };
+        assert_eq!(mixed_seconds.to_string(), "PT1.5S");
+    }
+
+    #[test]
+    fn try_add_period_positive() {
+        let mut sut = FirstDate::from_ymd_hms(2024, 1, 31, 22, 0, 0).unwrap();
+        sut.try_add_period(&Period::parse("P1M1DT3H").unwrap())
+            .unwrap();
+        // January 31st + 1 month is clamped to February 29th, then 1 day and 3 hours
+        assert_eq!(sut, FirstDate::from_ymd_hms(2024, 3, 2, 1, 0, 0).unwrap());
+
+        let mut sut = FirstDate::from_ymd(2024, 2, 29).unwrap();
+        sut.add_period(&Period::parse("P1Y").unwrap());
+        assert_eq!(sut, FirstDate::from_ymd(2025, 2, 28).unwrap());
+
+        let mut sut = FirstDate::from_ymd_hms_milli(2025, 5, 23, 3, 46, 48, 447).unwrap();
+        let period = Period::parse("P1Y2M10DT2H30M1.5S").unwrap();
+        sut.add_period(&period);
+        assert_eq!(
+            sut,
+            FirstDate::from_ymd_hms_milli(2026, 8, 2, 6, 16, 49, 947).unwrap()
+        );
+        sut.add_period(&period.checked_neg().unwrap());
+        assert_eq!(
+            sut,
+            FirstDate::from_ymd_hms_milli(2025, 5, 23, 3, 46, 48, 447).unwrap()
+        );
+    }
+
+    #[test]
+    fn try_add_period_keeps_timezone_positive() {
+        let mut sut = FirstDate::from_millis(1747972008447);
+        sut.set_timezone(String::from("America/Chicago"));
+        sut.add_period(&Period::parse("P1D").unwrap());
+        assert_eq!(sut.to_rfc3339(), "2025-05-23T22:46:48.447-05:00");
+    }
+
+    #[test]
+    fn try_add_period_across_daylight_savings_positive() {
+        // 2025-03-08T12:00:00-06:00, the clocks move forward on March 9th at 2 AM
+        let mut sut = FirstDate::from_millis(1741456800000);
+        sut.set_timezone(String::from("America/Chicago"));
+        let start = sut.clone();
+        sut.try_add_period(&Period::parse("PT24H").unwrap())
+            .unwrap();
+        assert_eq!(sut.to_rfc3339(), "2025-03-09T12:00:00.000-05:00");
+        assert_eq!(sut.is_daylight_savings, Some(true));
+        assert_eq!(sut.duration_since(&start), Duration::hours(23));
+
+        sut.try_add_period(&Period::parse("-P1D").unwrap()).unwrap();
+        assert_eq!(sut.to_rfc3339(), "2025-03-08T12:00:00.000-06:00");
+        assert_eq!(sut.is_daylight_savings, Some(false));
+    }
+
+    #[test]
+    fn try_add_period_negative() {
+        let mut sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        let too_far = Period {
+            years: 2_000_000,
+            ..Period::ZERO
+        };
+        assert_eq!(sut.try_add_period(&too_far), Err(FirstDateError::Overflow));
+        let too_many_days = Period {
+            days: i64::MAX,
+            ..Period::ZERO
+        };
+        assert_eq!(
+            sut.try_add_period(&too_many_days),
+            Err(FirstDateError::Overflow)
+        );
+        sut.add_period(&too_far);
+        assert_eq!(sut, FirstDate::from_ymd(2025, 5, 23).unwrap());
+    }
+}
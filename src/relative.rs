@@ -0,0 +1,294 @@
+use crate::date_utilities::{
+    TimeUnit, MILLISECONDS_IN_DAY, MILLISECONDS_IN_HOUR, MILLISECONDS_IN_MINUTE,
+};
+use crate::{Duration, FirstDate, FirstDateOperations};
+
+const MILLISECONDS_IN_SECOND: i64 = 1000;
+/// The average Gregorian year (365.2425 days) and month, used to round long durations.
+const MILLISECONDS_IN_YEAR: i64 = 31_556_952_000;
+const MILLISECONDS_IN_MONTH: i64 = MILLISECONDS_IN_YEAR / 12;
+
+/// The length of the words of `humanize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RelativeStyle {
+    /// "3 hours ago", "in 2 days".
+    #[default]
+    Long,
+    /// "3h ago", "in 2d".
+    Short,
+}
+
+/// Whether the duration is before or after the reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tense {
+    Past,
+    Future,
+}
+
+/// The options of `humanize_with`: the style and the thresholds where the next larger unit is used. The default is `RelativeOptions::LONG`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeOptions {
+    pub style: RelativeStyle,
+    /// Durations that round to this many seconds or more are written in minutes.
+    pub seconds: i64,
+    /// Durations that round to this many minutes or more are written in hours.
+    pub minutes: i64,
+    /// Durations that round to this many hours or more are written in days.
+    pub hours: i64,
+    /// Durations that round to this many days or more are written in months.
+    pub days: i64,
+    /// Durations that round to this many months or more are written in years.
+    pub months: i64,
+}
+
+impl RelativeOptions {
+    /// "3 hours ago", with the thresholds 45 seconds, 45 minutes, 22 hours, 26 days and 11 months.
+    pub const LONG: RelativeOptions = RelativeOptions {
+        style: RelativeStyle::Long,
+        seconds: 45,
+        minutes: 45,
+        hours: 22,
+        days: 26,
+        months: 11,
+    };
+
+    /// "3h ago", with the same thresholds as `LONG`.
+    pub const SHORT: RelativeOptions = RelativeOptions {
+        style: RelativeStyle::Short,
+        ..RelativeOptions::LONG
+    };
+}
+
+impl Default for RelativeOptions {
+    fn default() -> Self {
+        RelativeOptions::LONG
+    }
+}
+
+/// The words of `humanize_with`, implement it to write relative times in another language.
+pub trait RelativeWords {
+    /// The words for a non-zero `amount` of `unit`s in the past or the future, for example "3 hours ago". `unit` is never `TimeUnit::Millisecond`.
+    fn relative(&self, amount: i64, unit: TimeUnit, tense: Tense, style: RelativeStyle) -> String;
+    /// The words for a duration that rounds to 0 seconds, for example "just now".
+    fn now(&self, style: RelativeStyle) -> String;
+}
+
+/// The english words of `humanize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnglishRelativeWords;
+
+impl RelativeWords for EnglishRelativeWords {
+    fn relative(&self, amount: i64, unit: TimeUnit, tense: Tense, style: RelativeStyle) -> String {
+        let amount = match style {
+            RelativeStyle::Long => {
+                let name = match unit {
+                    TimeUnit::Year => "year",
+                    TimeUnit::Month => "month",
+                    TimeUnit::Day => "day",
+                    TimeUnit::Hour => "hour",
+                    TimeUnit::Minute => "minute",
+                    TimeUnit::Second | TimeUnit::Millisecond => "second",
+                };
+                let plural = if amount == 1 { "" } else { "s" };
+                format!("{} {}{}", amount, name, plural)
+            }
+            RelativeStyle::Short => {
+                let symbol = match unit {
+                    TimeUnit::Year => "y",
+                    TimeUnit::Month => "mo",
+                    TimeUnit::Day => "d",
+                    TimeUnit::Hour => "h",
+                    TimeUnit::Minute => "m",
+                    TimeUnit::Second | TimeUnit::Millisecond => "s",
+                };
+                format!("{}{}", amount, symbol)
+            }
+        };
+        match tense {
+            Tense::Past => format!("{} ago", amount),
+            Tense::Future => format!("in {}", amount),
+        }
+    }
+
+    fn now(&self, style: RelativeStyle) -> String {
+        match style {
+            RelativeStyle::Long => String::from("just now"),
+            RelativeStyle::Short => String::from("now"),
+        }
+    }
+}
+
+/// The amount of the unit, rounded to the nearest whole number (halves round up).
+fn rounded(millis: u64, unit_millis: i64) -> i64 {
+    let unit_millis = unit_millis as u64;
+    ((millis / unit_millis) + u64::from(millis % unit_millis >= unit_millis.div_ceil(2))) as i64
+}
+
+/// Picks the unit of the duration and rounds the amount: the largest unit that the thresholds allow, and at least 1 of it.
+fn amount_and_unit(millis: u64, options: &RelativeOptions) -> (i64, TimeUnit) {
+    let units = [
+        (TimeUnit::Second, MILLISECONDS_IN_SECOND, options.seconds),
+        (TimeUnit::Minute, MILLISECONDS_IN_MINUTE, options.minutes),
+        (TimeUnit::Hour, MILLISECONDS_IN_HOUR, options.hours),
+        (TimeUnit::Day, MILLISECONDS_IN_DAY, options.days),
+        (TimeUnit::Month, MILLISECONDS_IN_MONTH, options.months),
+    ];
+    for (unit, unit_millis, threshold) in units {
+        let amount = rounded(millis, unit_millis);
+        if amount < threshold {
+            return (amount.max(1), unit);
+        }
+    }
+    (rounded(millis, MILLISECONDS_IN_YEAR).max(1), TimeUnit::Year)
+}
+
+/// Writes the duration as a relative time in english, for example "in 2 days" for a positive duration and "3 hours ago" for a negative one. See `humanize_with` for the rounding.
+pub fn humanize(duration: Duration) -> String {
+    humanize_with(duration, &RelativeOptions::LONG, &EnglishRelativeWords)
+}
+
+/// Writes the duration as a relative time with the options and the words of a language. The duration is written in the largest unit allowed by the thresholds of the options, rounded to the nearest whole amount: with the default thresholds 90 minutes is "in 2 hours" and 23 hours is "in 1 day". Durations under half a second are "just now". Months and years use the average lengths of the Gregorian calendar.
+pub fn humanize_with(
+    duration: Duration,
+    options: &RelativeOptions,
+    words: &dyn RelativeWords,
+) -> String {
+    let millis = duration.whole_milliseconds();
+    if rounded(millis.unsigned_abs(), MILLISECONDS_IN_SECOND) == 0 {
+        return words.now(options.style);
+    }
+    let tense = if millis < 0 {
+        Tense::Past
+    } else {
+        Tense::Future
+    };
+    let (amount, unit) = amount_and_unit(millis.unsigned_abs(), options);
+    words.relative(amount, unit, tense, options.style)
+}
+
+impl FirstDate {
+    /// Describes the date relative to another one in english, for example "3 hours ago" when this date is 3 hours before `other` or "in 2 days" when it is 2 days after. `FirstDate::now()` is usually the other date.
+    pub fn relative_to(&self, other: &FirstDate) -> String {
+        humanize(self.duration_since(other))
+    }
+
+    /// Same as `relative_to` with the options and the words of `humanize_with`.
+    pub fn relative_to_with(
+        &self,
+        other: &FirstDate,
+        options: &RelativeOptions,
+        words: &dyn RelativeWords,
+    ) -> String {
+        humanize_with(self.duration_since(other), options, words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humanize_positive() {
+        let cases = [
+            (Duration::ZERO, "just now"),
+            (Duration::milliseconds(-499), "just now"),
+            (Duration::milliseconds(500), "in 1 second"),
+            (Duration::seconds(-30), "30 seconds ago"),
+            (Duration::seconds(44), "in 44 seconds"),
+            (Duration::seconds(45), "in 1 minute"),
+            (Duration::minutes(-3), "3 minutes ago"),
+            (Duration::minutes(45), "in 1 hour"),
+            (Duration::minutes(90), "in 2 hours"),
+            (Duration::hours(-3), "3 hours ago"),
+            (Duration::hours(22), "in 1 day"),
+            (Duration::days(2), "in 2 days"),
+            (Duration::days(-25), "25 days ago"),
+            (Duration::days(26), "in 1 month"),
+            (Duration::days(-100), "3 months ago"),
+            (Duration::days(300), "in 10 months"),
+            (Duration::days(335), "in 1 year"),
+            (Duration::days(-3653), "10 years ago"),
+            (Duration::milliseconds(i64::MIN), "292277025 years ago"),
+        ];
+        for (duration, expected) in cases {
+            assert_eq!(humanize(duration), expected, "{:?}", duration);
+        }
+    }
+
+    #[test]
+    fn humanize_with_positive() {
+        let short =
+            |duration| humanize_with(duration, &RelativeOptions::SHORT, &EnglishRelativeWords);
+        assert_eq!(short(Duration::hours(-3)), "3h ago");
+        assert_eq!(short(Duration::minutes(5)), "in 5m");
+        assert_eq!(short(Duration::days(-60)), "2mo ago");
+        assert_eq!(short(Duration::ZERO), "now");
+
+        let options = RelativeOptions {
+            hours: 48,
+            ..RelativeOptions::LONG
+        };
+        let words = EnglishRelativeWords;
+        assert_eq!(
+            humanize_with(Duration::hours(30), &options, &words),
+            "in 30 hours"
+        );
+        assert_eq!(
+            humanize_with(Duration::hours(48), &options, &words),
+            "in 2 days"
+        );
+    }
+
+    struct GermanWords;
+
+    impl RelativeWords for GermanWords {
+        fn relative(&self, amount: i64, unit: TimeUnit, tense: Tense, _: RelativeStyle) -> String {
+            let unit = match (unit, amount == 1) {
+                (TimeUnit::Hour, true) => "Stunde",
+                (TimeUnit::Hour, false) => "Stunden",
+                (TimeUnit::Day, true) => "Tag",
+                (TimeUnit::Day, false) => "Tagen",
+                _ => "Zeit",
+            };
+            match tense {
+                Tense::Past => format!("vor {} {}", amount, unit),
+                Tense::Future => format!("in {} {}", amount, unit),
+            }
+        }
+
+        fn now(&self, _: RelativeStyle) -> String {
+            String::from("gerade eben")
+        }
+    }
+
+    #[test]
+    fn relative_to_positive() {
+        let mock_millis: isize = 1747972008447;
+        let now = FirstDate::from_millis(mock_millis);
+        let mut sut = now.clone();
+        sut.add(-3, TimeUnit::Hour);
+        assert_eq!(sut.relative_to(&now), "3 hours ago");
+        assert_eq!(now.relative_to(&sut), "in 3 hours");
+        assert_eq!(now.relative_to(&now), "just now");
+
+        // the timezone does not change the instant
+        let mut other = now.clone();
+        other.set_timezone(String::from("Asia/Kathmandu"));
+        assert_eq!(other.relative_to(&now), "just now");
+
+        let options = RelativeOptions::LONG;
+        assert_eq!(
+            sut.relative_to_with(&now, &options, &GermanWords),
+            "vor 3 Stunden"
+        );
+        sut.add(2, TimeUnit::Day);
+        assert_eq!(
+            sut.relative_to_with(&now, &options, &GermanWords),
+            "in 2 Tagen"
+        );
+        assert_eq!(
+            now.relative_to_with(&now, &options, &GermanWords),
+            "gerade eben"
+        );
+    }
+}
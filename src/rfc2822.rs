@@ -0,0 +1,281 @@
+use crate::date_utilities::{check_field, total_offset, MAX_YEAR, MONTHS};
+use crate::error::{FirstDateError, ParseError};
+use crate::iso8601::with_time;
+use crate::scanner::Scanner;
+use crate::{FirstDate, FirstDateOperations, UtcOffset, Weekday};
+
+/// The obsolete zone names of RFC 2822 and their offsets in hours.
+const OBSOLETE_ZONES: [(&str, i8); 10] = [
+    ("UT", 0),
+    ("GMT", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+];
+
+impl FirstDate {
+    /// Formats the date for an email header (RFC 2822 / RFC 5322), for example "Fri, 23 May 2025 03:46:48 -0500". The offset includes daylight savings time.
+    /// Returns `FirstDateError::InvalidField` for the year if it is before 0 or after 9999, the format only has four digit years.
+    pub fn to_rfc2822(&self) -> Result<String, FirstDateError> {
+        check_field("year", self.year as i64, 0, 9999)?;
+        Ok(format!(
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} {}",
+            self.weekday().abbreviation(),
+            self.day,
+            &MONTHS[self.month as usize - 1].0[..3],
+            self.year,
+            self.hour,
+            self.minute,
+            self.second,
+            total_offset(self).to_string().replace(':', "")
+        ))
+    }
+
+    /// Parses an email date (RFC 2822 / RFC 5322), for example "Fri, 23 May 2025 03:46:48 -0500".
+    /// - The weekday and the seconds are optional, and the weekday must match the date.
+    /// - The obsolete zone names "UT", "GMT", "EST", "EDT", "CST", "CDT", "MST", "MDT", "PST" and "PDT" are read with their offsets. The military zone letters are read as UTC, as RFC 2822 recommends because their meaning was never consistent.
+    /// - Two digit years are 2000 to 2049 or 1950 to 1999 and three digit years are after 1900.
+    /// - Extra whitespace and comments in parentheses, such as "(CDT)", are skipped.
+    pub fn parse_rfc2822(value: &str) -> Result<FirstDate, ParseError> {
+        let mut scanner = Scanner::new(value);
+        skip_whitespace_and_comments(&mut scanner);
+
+        let mut weekday = None;
+        if scanner.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            let start = scanner.position();
+            let found = Weekday::ALL
+                .into_iter()
+                .find(|weekday| scanner.eat_ignore_case(weekday.abbreviation()))
+                .ok_or_else(|| scanner.unexpected("weekday"))?;
+            weekday = Some((found, start));
+            skip_whitespace_and_comments(&mut scanner);
+            scanner.expect(',', "','")?;
+            skip_whitespace_and_comments(&mut scanner);
+        }
+
+        let day_start = scanner.position();
+        let day = scanner.field_between(1, 2, "day", 1, 31)?;
+        skip_whitespace_and_comments(&mut scanner);
+        let month = scan_month_abbreviation(&mut scanner)?;
+        skip_whitespace_and_comments(&mut scanner);
+
+        let digits = scanner.digit_count();
+        let year = scanner.field_between(2, 6, "year", 0, MAX_YEAR)?;
+        let year = match digits {
+            2 if year < 50 => year + 2000,
+            2 | 3 => year + 1900,
+            _ => year,
+        };
+        skip_whitespace_and_comments(&mut scanner);
+
+        let hour = scanner.field(2, "hour", 0, 23)?;
+        skip_whitespace_and_comments(&mut scanner);
+        scanner.expect(':', "':'")?;
+        skip_whitespace_and_comments(&mut scanner);
+        let minute = scanner.field(2, "minute", 0, 59)?;
+        skip_whitespace_and_comments(&mut scanner);
+        let mut second = 0;
+        if scanner.eat(':') {
+            skip_whitespace_and_comments(&mut scanner);
+            second = scanner.field(2, "second", 0, 60)?.min(59);
+            skip_whitespace_and_comments(&mut scanner);
+        }
+
+        let offset = scan_zone(&mut scanner)?;
+        skip_whitespace_and_comments(&mut scanner);
+        scanner.finish()?;
+
+        let date = FirstDate::from_ymd(year as i32, month, day as u8)
+            .map_err(|_| ParseError::invalid_field(day_start, "day", day))?;
+        if let Some((weekday, position)) = weekday {
+            if date.weekday() != weekday {
+                let number = weekday.number_from_monday() as i64;
+                return Err(ParseError::invalid_field(position, "weekday", number));
+            }
+        }
+
+        let time = (hour as u8, minute as u8, second as u8, 0);
+        Ok(with_time(&date, time, offset))
+    }
+}
+
+/// Reads a three letter month abbreviation ("Jan" to "Dec", in any case) and returns the month number.
+pub fn scan_month_abbreviation(scanner: &mut Scanner) -> Result<u8, ParseError> {
+    (1..)
+        .zip(MONTHS.iter())
+        .find(|(_, (name, _))| scanner.eat_ignore_case(&name[..3]))
+        .map(|(month, _)| month)
+        .ok_or_else(|| scanner.unexpected("month"))
+}
+
+/// Skips whitespace and comments in parentheses (which can be nested).
+fn skip_whitespace_and_comments(scanner: &mut Scanner) {
+    scanner.skip_whitespace();
+    while scanner.peek() == Some('(') {
+        let mut depth = 0;
+        while let Some(next) = scanner.advance() {
+            match next {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                '\\' => {
+                    scanner.advance();
+                }
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+        }
+        scanner.skip_whitespace();
+    }
+}
+
+/// Reads "+hhmm", "-hhmm" or an obsolete zone name.
+fn scan_zone(scanner: &mut Scanner) -> Result<UtcOffset, ParseError> {
+    if let Some(sign) = scanner.eat_any(&['+', '-']) {
+        let hours = scanner.field(2, "offset hours", 0, 23)?;
+        let minutes = scanner.field(2, "offset minutes", 0, 59)?;
+        let seconds = (hours * 3600 + minutes * 60) as i32;
+        let seconds = if sign == '-' { -seconds } else { seconds };
+        return Ok(UtcOffset::from_seconds(seconds).unwrap_or(UtcOffset::UTC));
+    }
+
+    if let Some((_, hours)) = OBSOLETE_ZONES
+        .iter()
+        .find(|(name, _)| scanner.eat_ignore_case(name))
+    {
+        return Ok(UtcOffset::from_hms(*hours, 0, 0).unwrap_or(UtcOffset::UTC));
+    }
+
+    // the military zones, any single letter but J
+    let is_military = scanner
+        .peek()
+        .is_some_and(|c| c.is_ascii_alphabetic() && c != 'J' && c != 'j');
+    let is_single_letter = !matches!(
+        scanner.rest().chars().nth(1),
+        Some(c) if c.is_ascii_alphabetic()
+    );
+    if is_military && is_single_letter {
+        scanner.advance();
+        return Ok(UtcOffset::UTC);
+    }
+    Err(scanner.unexpected("zone"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+
+    #[test]
+    fn to_rfc2822_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(sut.to_rfc2822().unwrap(), "Fri, 23 May 2025 03:46:48 +0000");
+
+        sut.set_timezone(String::from("America/Chicago"));
+        assert_eq!(sut.to_rfc2822().unwrap(), "Thu, 22 May 2025 22:46:48 -0500");
+
+        let sut = FirstDate::from_ymd_hms(2025, 1, 9, 8, 5, 3).unwrap();
+        assert_eq!(sut.to_rfc2822().unwrap(), "Thu, 09 Jan 2025 08:05:03 +0000");
+
+        let sut = FirstDate::from_ymd(43, 3, 15).unwrap();
+        assert_eq!(sut.to_rfc2822().unwrap(), "Sun, 15 Mar 0043 00:00:00 +0000");
+    }
+
+    #[test]
+    fn to_rfc2822_negative() {
+        for year in [-43, -1, 10000] {
+            let sut = FirstDate::from_ymd(year, 3, 15).unwrap();
+            assert_eq!(
+                sut.to_rfc2822(),
+                Err(FirstDateError::InvalidField {
+                    field: "year",
+                    value: year as i64
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parse_rfc2822_positive() {
+        let expected = FirstDate::from_ymd_hms(2025, 5, 23, 8, 46, 48).unwrap();
+        for value in [
+            "Fri, 23 May 2025 03:46:48 -0500",
+            "23 May 2025 03:46:48 -0500",
+            "fri, 23 may 2025 03:46:48 -0500",
+            "  Fri,  23 May 2025 03:46:48 -0500 (CDT)",
+            "Fri, 23 May 2025 03:46:48 CDT",
+            "Fri, 23 May 2025 04:46:48 EDT",
+            "Fri, 23 May 2025 08:46:48 GMT",
+            "Fri, 23 May 2025 08:46:48 UT",
+            "Fri, 23 May 2025 08:46:48 Z",
+            "Fri, 23 May 2025 08:46:48 A",
+            "Fri, 23 May 25 03:46:48 -0500",
+            "Fri, 23 May 2025 03 : 46 : 48 -0500",
+            "Fri (the weekday), 23 May 2025 03:46:48 -0500",
+        ] {
+            assert_eq!(
+                FirstDate::parse_rfc2822(value),
+                Ok(expected.clone()),
+                "{}",
+                value
+            );
+        }
+
+        let sut = FirstDate::parse_rfc2822("Fri, 23 May 2025 03:46 -0500").unwrap();
+        assert_eq!((sut.hour, sut.minute, sut.second), (3, 46, 0));
+        assert_eq!(sut.timezone_offset.unwrap().to_string(), "-05:00");
+        let sut = FirstDate::parse_rfc2822("Sun, 23 May 99 03:46 +0000").unwrap();
+        assert_eq!(sut.year, 1999);
+        let sut = FirstDate::parse_rfc2822("Fri, 23 May 125 03:46 +0000").unwrap();
+        assert_eq!(sut.year, 2025);
+    }
+
+    #[test]
+    fn parse_rfc2822_round_trip_positive() {
+        let mut sut = FirstDate::from_millis(1747972008000);
+        sut.set_timezone(String::from("Asia/Kathmandu"));
+        let parsed = FirstDate::parse_rfc2822(&sut.to_rfc2822().unwrap()).unwrap();
+        assert_eq!(parsed, sut);
+        assert_eq!(parsed.to_rfc2822().unwrap(), sut.to_rfc2822().unwrap());
+    }
+
+    #[test]
+    fn parse_rfc2822_negative() {
+        assert_eq!(
+            FirstDate::parse_rfc2822("Thu, 23 May 2025 03:46:48 -0500"),
+            Err(ParseError::invalid_field(0, "weekday", 4))
+        );
+        assert_eq!(
+            FirstDate::parse_rfc2822("Fri, 31 Jun 2025 03:46:48 -0500"),
+            Err(ParseError::invalid_field(5, "day", 31))
+        );
+        assert_eq!(
+            FirstDate::parse_rfc2822("Fri, 23 May 2025 03:46:48 XYZ"),
+            Err(ParseError::new(
+                26,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'X',
+                    expected: "zone"
+                }
+            ))
+        );
+        for value in [
+            "",
+            "Fri 23 May 2025 03:46:48 -0500",
+            "Fri, 23 Mai 2025 03:46:48 -0500",
+            "Fri, 23 May 2025 03:46:48",
+            "Fri, 23 May 2025 3:46:48 -0500",
+            "Fri, 23 May 2025 03:46:48 -05:00",
+            "Fri, 23 May 2025 03:46:48 J",
+        ] {
+            assert!(FirstDate::parse_rfc2822(value).is_err(), "{}", value);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::error::{ParseError, ParseErrorKind};
+
+/// Reads a string from left to right for the parsers, keeping track of the byte position for the errors. Cloning it saves the position, to try reading something and go back if it does not match.
+#[derive(Clone)]
+pub struct Scanner<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Scanner<'a> {
+        Scanner { input, position: 0 }
+    }
+
+    /// The byte position of the next character.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The part of the input that has not been read yet.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn peek_is_digit(&self) -> bool {
+        self.peek().is_some_and(|c| c.is_ascii_digit())
+    }
+
+    /// The number of ASCII digits in a row from the current position.
+    pub fn digit_count(&self) -> usize {
+        self.rest()
+            .bytes()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    }
+
+    pub fn advance(&mut self) -> Option<char> {
+        let next = self.peek()?;
+        self.position += next.len_utf8();
+        Some(next)
+    }
+
+    /// Skips the character if it is the expected one.
+    pub fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            return true;
+        }
+        false
+    }
+
+    /// Skips the next character if it is one of the expected ones and returns it.
+    pub fn eat_any(&mut self, expected: &[char]) -> Option<char> {
+        let next = self.peek().filter(|c| expected.contains(c))?;
+        self.position += next.len_utf8();
+        Some(next)
+    }
+
+    /// Skips the text if the input continues with it, ignoring case ("MAI" and "mai", "ПЯТНИЦА" and "пятница").
+    pub fn eat_ignore_case(&mut self, expected: &str) -> bool {
+        let mut input = self.rest().chars();
+        let mut length = 0;
+        for expected in expected.chars() {
+            match input.next() {
+                Some(found) if found.to_lowercase().eq(expected.to_lowercase()) => {
+                    length += found.len_utf8();
+                }
+                _ => return false,
+            }
+        }
+        self.position += length;
+        true
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.advance();
+        }
+    }
+
+    pub fn expect(&mut self, expected: char, description: &'static str) -> Result<(), ParseError> {
+        if self.eat(expected) {
+            return Ok(());
+        }
+        Err(self.unexpected(description))
+    }
+
+    /// Reads a number of `min_digits` to `max_digits` digits.
+    pub fn number(
+        &mut self,
+        min_digits: usize,
+        max_digits: usize,
+        field: &'static str,
+    ) -> Result<i64, ParseError> {
+        let count = self.digit_count().min(max_digits);
+        if count < min_digits {
+            self.position += count;
+            return Err(self.unexpected(field));
+        }
+        let digits = &self.rest()[..count];
+        self.position += count;
+        Ok(digits
+            .bytes()
+            .fold(0, |value, b| value * 10 + (b - b'0') as i64))
+    }
+
+    /// Reads a number of exactly `digits` digits and checks that it is in the (inclusive) range.
+    pub fn field(
+        &mut self,
+        digits: usize,
+        field: &'static str,
+        min: i64,
+        max: i64,
+    ) -> Result<i64, ParseError> {
+        self.field_between(digits, digits, field, min, max)
+    }
+
+    /// Reads a number of `min_digits` to `max_digits` digits and checks that it is in the (inclusive) range.
+    pub fn field_between(
+        &mut self,
+        min_digits: usize,
+        max_digits: usize,
+        field: &'static str,
+        min: i64,
+        max: i64,
+    ) -> Result<i64, ParseError> {
+        let start = self.position;
+        let value = self.number(min_digits, max_digits, field)?;
+        if value < min || value > max {
+            return Err(ParseError::invalid_field(start, field, value));
+        }
+        Ok(value)
+    }
+
+    /// Reads the digits of a decimal fraction (after the separator) and returns it in milliseconds, digits past the milliseconds are dropped.
+    pub fn fraction_millis(&mut self) -> Result<u16, ParseError> {
+        let count = self.digit_count();
+        if count == 0 {
+            return Err(self.unexpected("fraction digits"));
+        }
+        let digits = format!("{:0<3}", &self.rest()[..count.min(3)]);
+        self.position += count;
+        Ok(digits.parse().unwrap_or(0))
+    }
+
+    /// Returns `ParseErrorKind::TrailingCharacters` if the input was not read to the end.
+    pub fn finish(&self) -> Result<(), ParseError> {
+        if self.is_at_end() {
+            return Ok(());
+        }
+        Err(ParseError::new(
+            self.position,
+            ParseErrorKind::TrailingCharacters,
+        ))
+    }
+
+    /// The error for finding the next character (or the end of the input) instead of what was expected.
+    pub fn unexpected(&self, expected: &'static str) -> ParseError {
+        let kind = match self.peek() {
+            Some(found) => ParseErrorKind::UnexpectedCharacter { found, expected },
+            None => ParseErrorKind::UnexpectedEnd { expected },
+        };
+        ParseError::new(self.position, kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_positive() {
+        let mut sut = Scanner::new("2025-05x");
+        assert_eq!(sut.number(4, 4, "year"), Ok(2025));
+        assert!(sut.eat('-'));
+        assert_eq!(sut.field(2, "month", 1, 12), Ok(5));
+        assert_eq!(sut.position(), 7);
+        assert_eq!(
+            sut.finish(),
+            Err(ParseError::new(7, ParseErrorKind::TrailingCharacters))
+        );
+    }
+
+    #[test]
+    fn number_negative() {
+        let mut sut = Scanner::new("20x5");
+        assert_eq!(
+            sut.number(4, 4, "year"),
+            Err(ParseError::new(
+                2,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'x',
+                    expected: "year"
+                }
+            ))
+        );
+
+        let mut sut = Scanner::new("13");
+        assert_eq!(
+            sut.field(2, "month", 1, 12),
+            Err(ParseError::invalid_field(0, "month", 13))
+        );
+
+        let mut sut = Scanner::new("1");
+        assert_eq!(
+            sut.field(2, "month", 1, 12),
+            Err(ParseError::new(
+                1,
+                ParseErrorKind::UnexpectedEnd { expected: "month" }
+            ))
+        );
+    }
+
+    #[test]
+    fn fraction_millis_positive() {
+        assert_eq!(Scanner::new("4").fraction_millis(), Ok(400));
+        assert_eq!(Scanner::new("447").fraction_millis(), Ok(447));
+        let mut sut = Scanner::new("123456789Z");
+        assert_eq!(sut.fraction_millis(), Ok(123));
+        assert_eq!(sut.peek(), Some('Z'));
+    }
+
+    #[test]
+    fn eat_ignore_case_positive() {
+        let mut sut = Scanner::new("gmt +1");
+        assert!(!sut.eat_ignore_case("UTC"));
+        assert!(sut.eat_ignore_case("GMT"));
+        sut.skip_whitespace();
+        assert_eq!(sut.rest(), "+1");
+
+        let mut sut = Scanner::new("ПЯТНИЦА, März");
+        assert!(sut.eat_ignore_case("пятница"));
+        assert!(sut.eat(','));
+        sut.skip_whitespace();
+        assert!(!sut.eat_ignore_case("märzen"));
+        assert!(sut.eat_ignore_case("MÄRZ"));
+        assert!(sut.is_at_end());
+    }
+}
@@ -0,0 +1,501 @@
+use crate::date_utilities::{format_iso_year, total_offset};
+use crate::error::{ParseError, ParseErrorKind};
+use crate::scanner::Scanner;
+use crate::{FirstDate, FirstDateOperations, Locale};
+
+/// How a number is padded to its width, chosen with a modifier after the `%`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding {
+    /// The default of the specifier, zeros for most numbers and spaces for `%e`.
+    Default,
+    /// `%-d`, no padding.
+    None,
+    /// `%_d`, padded with spaces.
+    Space,
+    /// `%0e`, padded with zeros.
+    Zero,
+}
+
+/// The value a specifier stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Specifier {
+    /// `%Y`, the year with at least four digits ("2025", "-0043").
+    Year,
+    /// `%y`, the last two digits of the year.
+    YearOfCentury,
+    /// `%G`, the ISO 8601 week-numbering year.
+    IsoYear,
+    /// `%m`, the month from 01 to 12.
+    Month,
+    /// `%b` or `%h`, the abbreviated month name ("May").
+    MonthAbbreviation,
+    /// `%B`, the full month name ("May").
+    MonthName,
+    /// `%d`, the day of the month from 01 to 31.
+    Day,
+    /// `%e`, the day of the month padded with a space (" 3").
+    DaySpacePadded,
+    /// `%j`, the day of the year from 001 to 366.
+    DayOfYear,
+    /// `%a`, the abbreviated weekday name ("Fri").
+    WeekdayAbbreviation,
+    /// `%A`, the full weekday name ("Friday").
+    WeekdayName,
+    /// `%u`, the weekday from 1 (Monday) to 7 (Sunday).
+    WeekdayFromMonday,
+    /// `%w`, the weekday from 0 (Sunday) to 6 (Saturday).
+    WeekdayFromSunday,
+    /// `%V`, the ISO 8601 week from 01 to 53.
+    IsoWeek,
+    /// `%H`, the hour from 00 to 23.
+    Hour,
+    /// `%I`, the hour from 01 to 12.
+    Hour12,
+    /// `%M`, the minute from 00 to 59.
+    Minute,
+    /// `%S`, the second from 00 to 59.
+    Second,
+    /// `%f`, the milliseconds from 000 to 999.
+    Millisecond,
+    /// `%p`, "AM" or "PM" (or the markers of the locale).
+    AmPm,
+    /// `%P`, "am" or "pm" (or the markers of the locale in lowercase).
+    AmPmLower,
+    /// `%z`, the offset from UTC including daylight savings time ("-0500").
+    Offset,
+    /// `%:z`, the offset from UTC with a colon ("-05:00").
+    OffsetColon,
+    /// `%Z`, the timezone name ("America/Chicago"), or the offset when no timezone was set.
+    TimezoneName,
+    /// `%s`, the number of seconds since Jan 1 1970 UTC.
+    UnixSeconds,
+}
+
+/// A piece of a compiled `Pattern`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternItem {
+    Literal(String),
+    Field {
+        specifier: Specifier,
+        padding: Padding,
+        uppercase: bool,
+    },
+}
+
+/// A strftime-style pattern compiled once, for formatting (and parsing) many dates with the same pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    items: Vec<PatternItem>,
+}
+
+impl Pattern {
+    /// Compiles a pattern such as "%Y-%m-%d %H:%M:%S". Returns a `ParseError` with the position of an unknown specifier (or of a `%` at the end of the pattern).
+    ///
+    /// Besides the specifiers of `Specifier`, `%F` is "%Y-%m-%d", `%T` is "%H:%M:%S", `%D` is "%m/%d/%y", `%R` is "%H:%M", and `%%`, `%n` and `%t` are a percent sign, a new line and a tab. The modifiers `-` (no padding), `_` (space padding), `0` (zero padding) and `^` (uppercase) go between the `%` and the specifier, for example "%-d".
+    pub fn compile(pattern: &str) -> Result<Pattern, ParseError> {
+        Pattern::compile_with(pattern, true)
+    }
+
+    /// Compiles the pattern, unknown specifiers are an error when `strict` and are kept as literal text otherwise.
+    fn compile_with(pattern: &str, strict: bool) -> Result<Pattern, ParseError> {
+        let mut scanner = Scanner::new(pattern);
+        let mut items: Vec<PatternItem> = Vec::new();
+        let mut literal = String::new();
+
+        while let Some(next) = scanner.peek() {
+            scanner.eat(next);
+            if next != '%' {
+                literal.push(next);
+                continue;
+            }
+            let percent_start = scanner.position() - 1;
+
+            let mut padding = Padding::Default;
+            let mut uppercase = false;
+            while let Some(modifier) = scanner.eat_any(&['-', '_', '0', '^']) {
+                match modifier {
+                    '-' => padding = Padding::None,
+                    '_' => padding = Padding::Space,
+                    '0' => padding = Padding::Zero,
+                    _ => uppercase = true,
+                }
+            }
+
+            let specifier_start = scanner.position();
+            let Some(specifier) = scanner.peek() else {
+                if strict {
+                    return Err(scanner.unexpected("format specifier"));
+                }
+                literal.push_str(&pattern[percent_start..]);
+                break;
+            };
+            scanner.eat(specifier);
+            let expanded = match specifier {
+                '%' => Some("%"),
+                'n' => Some("\n"),
+                't' => Some("\t"),
+                _ => None,
+            };
+            if let Some(text) = expanded {
+                literal.push_str(text);
+                continue;
+            }
+
+            let field = |specifier| PatternItem::Field {
+                specifier,
+                padding,
+                uppercase,
+            };
+            let fields = match specifier {
+                'F' => vec![
+                    field(Specifier::Year),
+                    PatternItem::Literal(String::from("-")),
+                    field(Specifier::Month),
+                    PatternItem::Literal(String::from("-")),
+                    field(Specifier::Day),
+                ],
+                'T' => vec![
+                    field(Specifier::Hour),
+                    PatternItem::Literal(String::from(":")),
+                    field(Specifier::Minute),
+                    PatternItem::Literal(String::from(":")),
+                    field(Specifier::Second),
+                ],
+                'D' => vec![
+                    field(Specifier::Month),
+                    PatternItem::Literal(String::from("/")),
+                    field(Specifier::Day),
+                    PatternItem::Literal(String::from("/")),
+                    field(Specifier::YearOfCentury),
+                ],
+                'R' => vec![
+                    field(Specifier::Hour),
+                    PatternItem::Literal(String::from(":")),
+                    field(Specifier::Minute),
+                ],
+                ':' if scanner.eat('z') => vec![field(Specifier::OffsetColon)],
+                _ => match specifier_of(specifier) {
+                    Some(specifier) => vec![field(specifier)],
+                    None if strict => {
+                        return Err(ParseError::new(
+                            specifier_start,
+                            ParseErrorKind::UnexpectedCharacter {
+                                found: specifier,
+                                expected: "format specifier",
+                            },
+                        ))
+                    }
+                    None => {
+                        literal.push_str(&pattern[percent_start..scanner.position()]);
+                        continue;
+                    }
+                },
+            };
+
+            if !literal.is_empty() {
+                items.push(PatternItem::Literal(std::mem::take(&mut literal)));
+            }
+            items.extend(fields);
+        }
+
+        if !literal.is_empty() {
+            items.push(PatternItem::Literal(literal));
+        }
+        Ok(Pattern { items })
+    }
+
+    /// The pieces of the pattern, in order.
+    pub fn items(&self) -> &[PatternItem] {
+        &self.items
+    }
+
+    /// Formats the date with the pattern, the names are in english.
+    pub fn format(&self, date: &FirstDate) -> String {
+        self.format_with_locale(date, &Locale::EN)
+    }
+
+    /// Formats the date with the pattern, using the month and weekday names and the AM/PM markers of the locale.
+    pub fn format_with_locale(&self, date: &FirstDate, locale: &Locale) -> String {
+        let mut formatted = String::new();
+        for item in &self.items {
+            match item {
+                PatternItem::Literal(text) => formatted.push_str(text),
+                PatternItem::Field {
+                    specifier,
+                    padding,
+                    uppercase,
+                } => {
+                    let value = format_field(date, locale, *specifier, *padding);
+                    if *uppercase {
+                        formatted.push_str(&value.to_uppercase());
+                    } else {
+                        formatted.push_str(&value);
+                    }
+                }
+            }
+        }
+        formatted
+    }
+}
+
+/// The specifier of a single pattern letter.
+fn specifier_of(letter: char) -> Option<Specifier> {
+    let specifier = match letter {
+        'Y' => Specifier::Year,
+        'y' => Specifier::YearOfCentury,
+        'G' => Specifier::IsoYear,
+        'm' => Specifier::Month,
+        'b' | 'h' => Specifier::MonthAbbreviation,
+        'B' => Specifier::MonthName,
+        'd' => Specifier::Day,
+        'e' => Specifier::DaySpacePadded,
+        'j' => Specifier::DayOfYear,
+        'a' => Specifier::WeekdayAbbreviation,
+        'A' => Specifier::WeekdayName,
+        'u' => Specifier::WeekdayFromMonday,
+        'w' => Specifier::WeekdayFromSunday,
+        'V' => Specifier::IsoWeek,
+        'H' => Specifier::Hour,
+        'I' => Specifier::Hour12,
+        'M' => Specifier::Minute,
+        'S' => Specifier::Second,
+        'f' => Specifier::Millisecond,
+        'p' => Specifier::AmPm,
+        'P' => Specifier::AmPmLower,
+        'z' => Specifier::Offset,
+        'Z' => Specifier::TimezoneName,
+        's' => Specifier::UnixSeconds,
+        _ => return None,
+    };
+    Some(specifier)
+}
+
+/// Pads a number to the width with the padding (or the default padding character).
+fn pad(value: i64, width: usize, padding: Padding, default: char) -> String {
+    let fill = match padding {
+        Padding::Default => default,
+        Padding::None => return value.to_string(),
+        Padding::Space => ' ',
+        Padding::Zero => '0',
+    };
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    let fill_count = width.saturating_sub(digits.len() + sign.len());
+    let fill = fill.to_string().repeat(fill_count);
+    if fill.starts_with('0') {
+        format!("{}{}{}", sign, fill, digits)
+    } else {
+        format!("{}{}{}", fill, sign, digits)
+    }
+}
+
+fn format_field(
+    date: &FirstDate,
+    locale: &Locale,
+    specifier: Specifier,
+    padding: Padding,
+) -> String {
+    let number = |value: i64, width: usize| pad(value, width, padding, '0');
+    let hour_12 = match date.hour % 12 {
+        0 => 12,
+        hour => hour,
+    };
+    match specifier {
+        Specifier::Year if padding == Padding::Default => format_iso_year(date.year as i64),
+        Specifier::Year => number(date.year as i64, 4),
+        Specifier::YearOfCentury => number((date.year as i64).rem_euclid(100), 2),
+        Specifier::IsoYear if padding == Padding::Default => {
+            format_iso_year(date.iso_week().year as i64)
+        }
+        Specifier::IsoYear => number(date.iso_week().year as i64, 4),
+        Specifier::Month => number(date.month as i64, 2),
+        Specifier::MonthAbbreviation => locale.month_abbreviation(date.month).to_string(),
+        Specifier::MonthName => locale.month_name(date.month).to_string(),
+        Specifier::Day => number(date.day as i64, 2),
+        Specifier::DaySpacePadded => pad(date.day as i64, 2, padding, ' '),
+        Specifier::DayOfYear => number(date.day_of_year() as i64, 3),
+        Specifier::WeekdayAbbreviation => locale.weekday_abbreviation(date.weekday()).to_string(),
+        Specifier::WeekdayName => locale.weekday_name(date.weekday()).to_string(),
+        Specifier::WeekdayFromMonday => number(date.weekday().number_from_monday() as i64, 1),
+        Specifier::WeekdayFromSunday => number(date.weekday().number_from_sunday() as i64 - 1, 1),
+        Specifier::IsoWeek => number(date.iso_week().week as i64, 2),
+        Specifier::Hour => number(date.hour as i64, 2),
+        Specifier::Hour12 => number(hour_12 as i64, 2),
+        Specifier::Minute => number(date.minute as i64, 2),
+        Specifier::Second => number(date.second as i64, 2),
+        Specifier::Millisecond => number(date.millisecond as i64, 3),
+        Specifier::AmPm => String::from(if date.hour < 12 { locale.am } else { locale.pm }),
+        Specifier::AmPmLower => {
+            let marker = if date.hour < 12 { locale.am } else { locale.pm };
+            marker.to_lowercase()
+        }
+        Specifier::Offset => total_offset(date).to_string().replace(':', ""),
+        Specifier::OffsetColon => total_offset(date).to_string(),
+        Specifier::TimezoneName => timezone_name_of(date),
+        Specifier::UnixSeconds => number(date.instant().millis().div_euclid(1000), 1),
+    }
+}
+
+/// The timezone name for `%Z`: the name given to `set_timezone`, otherwise "UTC" or the offset.
+fn timezone_name_of(date: &FirstDate) -> String {
+    let offset = total_offset(date);
+    if date.timezone_name != "unset" {
+        date.timezone_name.clone()
+    } else if offset.is_utc() {
+        String::from("UTC")
+    } else {
+        offset.to_string()
+    }
+}
+
+impl FirstDate {
+    /// Formats the date with a strftime-style pattern, for example `format("%A %B %-d, %Y %I:%M %p")` is "Friday May 23, 2025 03:46 AM". See `Pattern::compile` for the specifiers. Unknown specifiers are written as they are; compile a `Pattern` to get an error for them instead (and to format many dates faster).
+    pub fn format(&self, pattern: &str) -> String {
+        Pattern::compile_with(pattern, false)
+            .map(|pattern| pattern.format(self))
+            .unwrap_or_default()
+    }
+
+    /// Same as `format` with the names and AM/PM markers of the locale, for example `format_with_locale("%A %-d %B %Y", &Locale::FR)` is "vendredi 23 mai 2025".
+    pub fn format_with_locale(&self, pattern: &str, locale: &Locale) -> String {
+        Pattern::compile_with(pattern, false)
+            .map(|pattern| pattern.format_with_locale(self, locale))
+            .unwrap_or_default()
+    }
+
+    /// Formats the date with the `date_time_pattern` of the locale, for example "Freitag, 23. Mai 2025 03:46:48" in German.
+    pub fn to_locale_string(&self, locale: &Locale) -> String {
+        self.format_with_locale(locale.date_time_pattern, locale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_positive() {
+        let mock_millis: isize = 1747972008447;
+        let sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(
+            sut.format("%Y-%m-%d %H:%M:%S.%f %z"),
+            "2025-05-23 03:46:48.447 +0000"
+        );
+        assert_eq!(
+            sut.format("%A %B %-d, %Y %I:%M %p"),
+            "Friday May 23, 2025 03:46 AM"
+        );
+        assert_eq!(
+            sut.format("%a %b %e %j %u %w %V %G"),
+            "Fri May 23 143 5 5 21 2025"
+        );
+        assert_eq!(
+            sut.format("%F %T %D %R %%"),
+            "2025-05-23 03:46:48 05/23/25 03:46 %"
+        );
+        assert_eq!(sut.format("%s %Z"), "1747972008 UTC");
+        assert_eq!(sut.format("%^a %^B"), "FRI MAY");
+    }
+
+    #[test]
+    fn format_padding_positive() {
+        let sut = FirstDate::from_ymd_hms(2025, 1, 9, 0, 5, 7).unwrap();
+        assert_eq!(sut.format("%d|%-d|%_d|%e|%0e|%-e"), "09|9| 9| 9|09|9");
+        assert_eq!(sut.format("%I %-I %p %P"), "12 12 AM am");
+        assert_eq!(sut.format("%j %-j %_j"), "009 9   9");
+        assert_eq!(sut.format("%-H:%M:%S"), "0:05:07");
+    }
+
+    #[test]
+    fn format_timezone_positive() {
+        let mock_millis: isize = 1747972008447;
+        let mut sut = FirstDate::from_millis(mock_millis);
+        sut.set_timezone(String::from("America/Chicago"));
+        assert_eq!(
+            sut.format("%H:%M %z %:z %Z"),
+            "22:46 -0500 -05:00 America/Chicago"
+        );
+    }
+
+    #[test]
+    fn format_bc_year_positive() {
+        let sut = FirstDate::from_ymd(-43, 3, 15).unwrap();
+        assert_eq!(sut.format("%Y|%-Y|%y"), "-0043|-43|57");
+    }
+
+    #[test]
+    fn format_unknown_specifier_positive() {
+        let sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        assert_eq!(sut.format("%Y %Q %-Q %d %_"), "2025 %Q %-Q 23 %_");
+    }
+
+    #[test]
+    fn compile_negative() {
+        assert_eq!(
+            Pattern::compile("%Y-%Q"),
+            Err(ParseError::new(
+                4,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'Q',
+                    expected: "format specifier"
+                }
+            ))
+        );
+        assert_eq!(
+            Pattern::compile("%Y-%-"),
+            Err(ParseError::new(
+                5,
+                ParseErrorKind::UnexpectedEnd {
+                    expected: "format specifier"
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn format_with_locale_positive() {
+        let mock_millis: isize = 1747972008447;
+        let sut = FirstDate::from_millis(mock_millis);
+        assert_eq!(
+            sut.format_with_locale("%A %-d %B %Y", &Locale::FR),
+            "vendredi 23 mai 2025"
+        );
+        assert_eq!(sut.format_with_locale("%a %b", &Locale::DE), "Fr Mai");
+        assert_eq!(sut.format_with_locale("%^A", &Locale::RU), "ПЯТНИЦА");
+        assert_eq!(
+            sut.format_with_locale("%I:%M %p", &Locale::JA),
+            "03:46 午前"
+        );
+        assert_eq!(sut.format_with_locale("%I %P", &Locale::ES), "03 a. m.");
+        assert_eq!(
+            sut.format_with_locale("%A %B %p", &Locale::EN),
+            sut.format("%A %B %p")
+        );
+
+        assert_eq!(
+            sut.to_locale_string(&Locale::EN),
+            "Friday, May 23, 2025 03:46:48 AM"
+        );
+        assert_eq!(
+            sut.to_locale_string(&Locale::DE),
+            "Freitag, 23. Mai 2025 03:46:48"
+        );
+        assert_eq!(
+            sut.to_locale_string(&Locale::ZH),
+            "2025年5月23日 星期五 03:46:48"
+        );
+    }
+
+    #[test]
+    fn compiled_pattern_positive() {
+        let pattern = Pattern::compile("%d/%m/%Y").unwrap();
+        assert_eq!(pattern.items().len(), 5);
+        let mut sut = FirstDate::from_ymd(2025, 5, 23).unwrap();
+        let mut formatted = Vec::new();
+        for _ in 0..3 {
+            formatted.push(pattern.format(&sut));
+            sut.add(1, crate::TimeUnit::Day);
+        }
+        assert_eq!(formatted, ["23/05/2025", "24/05/2025", "25/05/2025"]);
+    }
+}
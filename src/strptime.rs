@@ -0,0 +1,502 @@
+use crate::date_utilities::{
+    local_epoch_millis, local_time_type_at_wall_clock, max_local_millis, min_local_millis,
+    timezone_info, MAX_YEAR, MIN_YEAR,
+};
+use crate::error::{ParseError, ParseErrorKind};
+use crate::iso8601::{scan_utc_offset, with_time};
+use crate::scanner::Scanner;
+use crate::strftime::{Pattern, PatternItem, Specifier};
+use crate::tzif::TimeZoneInfo;
+use crate::{FirstDate, FirstDateOperations, Instant, Locale, UtcOffset, Weekday};
+
+/// A field read from the input: the specifier, its value and the byte position where it started.
+type ParsedField = (Specifier, i64, usize);
+/// A timezone name read by `%Z`: the name, its zone data and the byte position where it started.
+type ParsedTimezone = (String, TimeZoneInfo, usize);
+
+impl Pattern {
+    /// Parses the input with the pattern, the inverse of `format`. See `FirstDate::parse_with`.
+    pub fn parse(&self, input: &str) -> Result<FirstDate, ParseError> {
+        self.parse_with_locale(input, &Locale::EN)
+    }
+
+    /// Same as `parse` with the month and weekday names and the AM/PM markers of the locale.
+    pub fn parse_with_locale(&self, input: &str, locale: &Locale) -> Result<FirstDate, ParseError> {
+        let mut scanner = Scanner::new(input);
+        let mut fields: Vec<ParsedField> = Vec::new();
+        let mut offset: Option<UtcOffset> = None;
+        let mut timezone: Option<ParsedTimezone> = None;
+
+        for item in self.items() {
+            match item {
+                PatternItem::Literal(text) => scan_literal(&mut scanner, text)?,
+                PatternItem::Field { specifier, .. } => {
+                    let start = scanner.position();
+                    match specifier {
+                        Specifier::Offset | Specifier::OffsetColon => {
+                            offset = Some(scan_utc_offset(&mut scanner)?);
+                        }
+                        Specifier::TimezoneName => {
+                            timezone = scan_timezone_name(&mut scanner)?;
+                        }
+                        _ => {
+                            let value = scan_field(&mut scanner, locale, *specifier)?;
+                            fields.push((*specifier, value, start));
+                        }
+                    }
+                }
+            }
+        }
+        scanner.finish()?;
+
+        let mut date = resolve(&fields, offset.unwrap_or(UtcOffset::UTC))?;
+        if let Some((timezone_name, zone, position)) = timezone {
+            if offset.is_none() {
+                date = in_zone(&date, &zone).ok_or_else(|| {
+                    ParseError::new(
+                        position,
+                        ParseErrorKind::UnknownTimezone(timezone_name.clone()),
+                    )
+                })?;
+            }
+            date.timezone_name = timezone_name;
+        }
+        Ok(date)
+    }
+}
+
+impl FirstDate {
+    /// Parses the input with a strftime-style pattern (see `Pattern::compile`), for example `FirstDate::parse_with("Friday May 23, 2025 3:46 PM", "%A %B %d, %Y %I:%M %p")`.
+    ///
+    /// - Month and weekday names can be full or abbreviated in any case, for all of `%b`, `%B`, `%a` and `%A`.
+    /// - Numbers may be written without padding, and a space in the pattern matches any amount of whitespace.
+    /// - `%z` reads "Z", "+hh", "+hhmm" or "+hh:mm" and `%Z` reads "UTC", "GMT" or a timezone name such as "America/Chicago" (its offset at the parsed time, including daylight savings time, is used unless there is also a `%z`).
+    /// - `%y` is a year from 1969 to 2068. Missing date fields default to January 1st 1970 and missing time fields to 0. A weekday that does not match the date is an error.
+    ///
+    /// Errors in the pattern itself are returned like `Pattern::compile` does, with the position in the pattern.
+    pub fn parse_with(input: &str, pattern: &str) -> Result<FirstDate, ParseError> {
+        Pattern::compile(pattern)?.parse(input)
+    }
+
+    /// Same as `parse_with` with the names and AM/PM markers of the locale, for example `FirstDate::parse_with_locale("vendredi 23 mai 2025", "%A %d %B %Y", &Locale::FR)`.
+    pub fn parse_with_locale(
+        input: &str,
+        pattern: &str,
+        locale: &Locale,
+    ) -> Result<FirstDate, ParseError> {
+        Pattern::compile(pattern)?.parse_with_locale(input, locale)
+    }
+}
+
+/// Matches literal text of the pattern, whitespace in the pattern matches any amount of whitespace (even none).
+fn scan_literal(scanner: &mut Scanner, text: &str) -> Result<(), ParseError> {
+    for expected in text.chars() {
+        if expected.is_whitespace() {
+            scanner.skip_whitespace();
+        } else if !scanner.eat(expected) {
+            return Err(scanner.unexpected("text of the pattern"));
+        }
+    }
+    Ok(())
+}
+
+/// Reads the first of the names (ignoring case) and returns its value.
+fn scan_name(
+    scanner: &mut Scanner,
+    names: &[(&str, i64)],
+    expected: &'static str,
+) -> Result<i64, ParseError> {
+    names
+        .iter()
+        .find(|(name, _)| scanner.eat_ignore_case(name))
+        .map(|(_, value)| *value)
+        .ok_or_else(|| scanner.unexpected(expected))
+}
+
+/// The full names then the abbreviations of the months of the locale, numbered from 1.
+fn month_names(locale: &Locale) -> Vec<(&'static str, i64)> {
+    let full = locale.months.into_iter().zip(1..);
+    let abbreviated = locale.month_abbreviations.into_iter().zip(1..);
+    full.chain(abbreviated).collect()
+}
+
+/// The full names then the abbreviations of the weekdays of the locale, numbered from 1 (Monday).
+fn weekday_names(locale: &Locale) -> Vec<(&'static str, i64)> {
+    let numbered = |weekday: Weekday| weekday.number_from_monday() as i64;
+    let full = Weekday::ALL.map(|weekday| (locale.weekday_name(weekday), numbered(weekday)));
+    let abbreviated =
+        Weekday::ALL.map(|weekday| (locale.weekday_abbreviation(weekday), numbered(weekday)));
+    full.into_iter().chain(abbreviated).collect()
+}
+
+fn scan_field(
+    scanner: &mut Scanner,
+    locale: &Locale,
+    specifier: Specifier,
+) -> Result<i64, ParseError> {
+    match specifier {
+        Specifier::Year | Specifier::IsoYear => {
+            let sign = scanner.eat_any(&['+', '-']);
+            let max_digits = if sign.is_some() { 7 } else { 4 };
+            let year = scanner.number(1, max_digits, "year")?;
+            Ok(if sign == Some('-') { -year } else { year })
+        }
+        Specifier::YearOfCentury => scanner.field_between(1, 2, "year", 0, 99),
+        Specifier::Month => scanner.field_between(1, 2, "month", 1, 12),
+        Specifier::MonthAbbreviation | Specifier::MonthName => {
+            scan_name(scanner, &month_names(locale), "month name")
+        }
+        Specifier::Day => scanner.field_between(1, 2, "day", 1, 31),
+        Specifier::DaySpacePadded => {
+            scanner.eat(' ');
+            scanner.field_between(1, 2, "day", 1, 31)
+        }
+        Specifier::DayOfYear => scanner.field_between(1, 3, "day of year", 1, 366),
+        Specifier::WeekdayAbbreviation | Specifier::WeekdayName => {
+            scan_name(scanner, &weekday_names(locale), "weekday name")
+        }
+        Specifier::WeekdayFromMonday => scanner.field(1, "weekday", 1, 7),
+        // stored like %u, Sunday is 7
+        Specifier::WeekdayFromSunday => match scanner.field(1, "weekday", 0, 6)? {
+            0 => Ok(7),
+            weekday => Ok(weekday),
+        },
+        Specifier::IsoWeek => scanner.field_between(1, 2, "iso week", 1, 53),
+        Specifier::Hour => scanner.field_between(1, 2, "hour", 0, 23),
+        Specifier::Hour12 => scanner.field_between(1, 2, "hour", 1, 12),
+        Specifier::Minute => scanner.field_between(1, 2, "minute", 0, 59),
+        Specifier::Second => scanner.field_between(1, 2, "second", 0, 59),
+        Specifier::Millisecond => scanner.fraction_millis().map(|millis| millis as i64),
+        Specifier::AmPm | Specifier::AmPmLower => {
+            scan_name(scanner, &[(locale.am, 0), (locale.pm, 1)], "AM or PM")
+        }
+        Specifier::UnixSeconds => {
+            let sign = scanner.eat('-');
+            let seconds = scanner.number(1, 15, "seconds")?;
+            Ok(if sign { -seconds } else { seconds })
+        }
+        // read by `parse` itself
+        Specifier::Offset | Specifier::OffsetColon | Specifier::TimezoneName => {
+            Err(scanner.unexpected("field"))
+        }
+    }
+}
+
+/// Reads "UTC", "GMT", "UT", "Z" or a timezone name such as "America/Chicago", and returns the name and its zone data (`None` for UTC).
+/// Names can contain signs and digits ("Etc/GMT+5", "America/Port-au-Prince"), when the whole name is unknown a signed number at its end is left for a following `%z`, so "UTC+02:00" reads "UTC".
+fn scan_timezone_name(scanner: &mut Scanner) -> Result<Option<ParsedTimezone>, ParseError> {
+    let start = scanner.position();
+    let length = scanner
+        .rest()
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '/' | '_' | '-' | '+')))
+        .unwrap_or(scanner.rest().len());
+    let full_name = &scanner.rest()[..length];
+    if full_name.is_empty() {
+        return Err(scanner.unexpected("timezone name"));
+    }
+    let without_offset = full_name
+        .trim_end_matches(|c: char| c.is_ascii_digit())
+        .strip_suffix(['+', '-'])
+        .unwrap_or(full_name);
+
+    for name in [full_name, without_offset] {
+        if name.is_empty() {
+            continue;
+        }
+        if ["UTC", "GMT", "UT", "Z"]
+            .iter()
+            .any(|utc| utc.eq_ignore_ascii_case(name))
+        {
+            scanner.eat_ignore_case(name);
+            return Ok(None);
+        }
+        if let Ok(zone) = timezone_info(name) {
+            let name = name.to_string();
+            scanner.eat_ignore_case(&name);
+            return Ok(Some((name, zone, start)));
+        }
+    }
+    Err(ParseError::new(
+        start,
+        ParseErrorKind::UnknownTimezone(full_name.to_string()),
+    ))
+}
+
+/// The date with the same wall clock time in the zone, using the offset (and daylight savings time) the zone has at that time. `None` if the zone data has an offset of a day or more.
+fn in_zone(date: &FirstDate, zone: &TimeZoneInfo) -> Option<FirstDate> {
+    let local_millis = local_epoch_millis(date);
+    let local_time_type = local_time_type_at_wall_clock(zone, local_millis);
+    let offset = UtcOffset::from_seconds(local_time_type.utc_offset)?;
+    let utc_millis = local_millis - offset.whole_seconds() as i64 * 1000;
+    let mut date = FirstDate::from_instant(Instant::new(utc_millis, offset));
+    date.is_daylight_savings = Some(local_time_type.is_dst);
+    Some(date)
+}
+
+/// Builds the date out of the fields that were read.
+fn resolve(fields: &[ParsedField], offset: UtcOffset) -> Result<FirstDate, ParseError> {
+    let get = |specifier: Specifier| {
+        fields
+            .iter()
+            .rev()
+            .find(|(field, _, _)| *field == specifier)
+            .map(|(_, value, position)| (*value, *position))
+    };
+    let value_of =
+        |specifiers: &[Specifier]| specifiers.iter().find_map(|specifier| get(*specifier));
+    let millisecond = get(Specifier::Millisecond).map_or(0, |(millis, _)| millis);
+
+    if let Some((seconds, position)) = get(Specifier::UnixSeconds) {
+        let instant = Instant::new(seconds * 1000 + millisecond, offset);
+        if !(min_local_millis()..=max_local_millis()).contains(&instant.local_millis()) {
+            return Err(ParseError::invalid_field(position, "seconds", seconds));
+        }
+        return Ok(FirstDate::from_instant(instant));
+    }
+
+    let year = match (get(Specifier::Year), get(Specifier::YearOfCentury)) {
+        (Some(year), _) => year,
+        (None, Some((year, position))) if year < 69 => (2000 + year, position),
+        (None, Some((year, position))) => (1900 + year, position),
+        (None, None) => (1970, 0),
+    };
+    let weekday = value_of(&[
+        Specifier::WeekdayFromMonday,
+        Specifier::WeekdayFromSunday,
+        Specifier::WeekdayName,
+        Specifier::WeekdayAbbreviation,
+    ]);
+    let month = value_of(&[
+        Specifier::Month,
+        Specifier::MonthName,
+        Specifier::MonthAbbreviation,
+    ]);
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year.0) {
+        return Err(ParseError::invalid_field(year.1, "year", year.0));
+    }
+
+    let date = if let (Some((iso_year, _)), Some((week, position))) =
+        (get(Specifier::IsoYear), get(Specifier::IsoWeek))
+    {
+        let weekday = Weekday::ALL[weekday.map_or(1, |(weekday, _)| weekday) as usize - 1];
+        FirstDate::from_iso_week_date(iso_year as i32, week as u8, weekday)
+            .map_err(|_| ParseError::invalid_field(position, "iso week", week))?
+    } else if let (Some((day_of_year, position)), None) = (get(Specifier::DayOfYear), month) {
+        FirstDate::from_ordinal(year.0 as i32, day_of_year as u16)
+            .map_err(|_| ParseError::invalid_field(position, "day of year", day_of_year))?
+    } else {
+        let (month, _) = month.unwrap_or((1, 0));
+        let (day, day_position) =
+            value_of(&[Specifier::Day, Specifier::DaySpacePadded]).unwrap_or((1, 0));
+        FirstDate::from_ymd(year.0 as i32, month as u8, day as u8)
+            .map_err(|_| ParseError::invalid_field(day_position, "day", day))?
+    };
+
+    if let Some((weekday, position)) = weekday {
+        if date.weekday().number_from_monday() as i64 != weekday {
+            return Err(ParseError::invalid_field(position, "weekday", weekday));
+        }
+    }
+
+    let is_pm = value_of(&[Specifier::AmPm, Specifier::AmPmLower]).is_some_and(|(pm, _)| pm == 1);
+    let hour = match get(Specifier::Hour12) {
+        Some((hour, _)) => hour % 12 + if is_pm { 12 } else { 0 },
+        None => get(Specifier::Hour).map_or(0, |(hour, _)| hour),
+    };
+    let minute = get(Specifier::Minute).map_or(0, |(minute, _)| minute);
+    let second = get(Specifier::Second).map_or(0, |(second, _)| second);
+
+    let time = (hour as u8, minute as u8, second as u8, millisecond as u16);
+    Ok(with_time(&date, time, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_with_positive() {
+        let expected = FirstDate::from_ymd_hms_milli(2025, 5, 23, 15, 46, 48, 447).unwrap();
+        let cases = [
+            ("2025-05-23 15:46:48.447", "%Y-%m-%d %H:%M:%S.%f"),
+            ("23/05/2025 15:46:48.447", "%d/%m/%Y %T.%f"),
+            (
+                "Friday May 23, 2025 3:46:48.447 PM",
+                "%A %B %d, %Y %I:%M:%S.%f %p",
+            ),
+            (
+                "fri, 23 MAY 25 03:46:48.447 pm",
+                "%a, %d %b %y %I:%M:%S.%f %P",
+            ),
+            ("Fri May 23 15:46:48.447 2025", "%a %b %e %H:%M:%S.%f %Y"),
+            ("2025-143T15:46:48.447Z", "%Y-%jT%H:%M:%S.%f%z"),
+            ("2025-05-23T10:46:48.447-05:00", "%FT%T.%f%:z"),
+            ("1748015208.447", "%s.%f"),
+            ("2025-W21-5 15:46:48.447", "%G-W%V-%u %T.%f"),
+        ];
+        for (input, pattern) in cases {
+            assert_eq!(
+                FirstDate::parse_with(input, pattern),
+                Ok(expected.clone()),
+                "{} with {}",
+                input,
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn parse_with_twelve_hour_positive() {
+        let sut = FirstDate::parse_with("12:05 AM", "%I:%M %p").unwrap();
+        assert_eq!((sut.year, sut.month, sut.day, sut.hour), (1970, 1, 1, 0));
+        let sut = FirstDate::parse_with("12:05 PM", "%I:%M %p").unwrap();
+        assert_eq!(sut.hour, 12);
+        let sut = FirstDate::parse_with("1:05 pm", "%I:%M %p").unwrap();
+        assert_eq!(sut.hour, 13);
+    }
+
+    #[test]
+    fn parse_with_timezone_name_positive() {
+        let sut =
+            FirstDate::parse_with("2025-05-23 09:16 Asia/Kolkata", "%Y-%m-%d %H:%M %Z").unwrap();
+        assert_eq!(sut.timezone_name, "Asia/Kolkata");
+        assert_eq!(sut.timezone_offset.unwrap().to_string(), "+05:30");
+        assert_eq!((sut.hour, sut.minute), (9, 16));
+        assert_eq!(sut.to_millis(), 1747972008447 - 48447);
+
+        let sut = FirstDate::parse_with("2025-05-23 03:46 gmt", "%Y-%m-%d %H:%M %Z").unwrap();
+        assert_eq!(sut.timezone_offset, Some(UtcOffset::UTC));
+        assert_eq!(sut.timezone_name, "unset");
+
+        let sut = FirstDate::parse_with("2025-05-22 22:46 Etc/GMT+5", "%Y-%m-%d %H:%M %Z").unwrap();
+        assert_eq!(sut.timezone_name, "Etc/GMT+5");
+        assert_eq!(sut.timezone_offset, UtcOffset::from_hms(-5, 0, 0));
+        assert_eq!(sut.to_millis(), 1747972008447 - 48447);
+    }
+
+    #[test]
+    fn parse_with_timezone_name_and_offset_positive() {
+        let sut =
+            FirstDate::parse_with("2025-05-23 05:46 UTC+02:00", "%Y-%m-%d %H:%M %Z%:z").unwrap();
+        assert_eq!(sut.timezone_name, "unset");
+        assert_eq!(sut.timezone_offset, UtcOffset::from_hms(2, 0, 0));
+        assert_eq!(sut.to_millis(), 1747972008447 - 48447);
+
+        let sut = FirstDate::parse_with(
+            "2025-05-22 22:46 America/Chicago-0500",
+            "%Y-%m-%d %H:%M %Z%z",
+        )
+        .unwrap();
+        assert_eq!(sut.timezone_name, "America/Chicago");
+        assert_eq!(sut.timezone_offset, UtcOffset::from_hms(-5, 0, 0));
+        assert_eq!(sut.to_millis(), 1747972008447 - 48447);
+    }
+
+    #[test]
+    fn parse_with_negative() {
+        assert_eq!(
+            FirstDate::parse_with("2025-13-23", "%Y-%m-%d"),
+            Err(ParseError::invalid_field(5, "month", 13))
+        );
+        assert_eq!(
+            FirstDate::parse_with("2025-02-29", "%Y-%m-%d"),
+            Err(ParseError::invalid_field(8, "day", 29))
+        );
+        assert_eq!(
+            FirstDate::parse_with("Thu May 23 2025", "%a %b %d %Y"),
+            Err(ParseError::invalid_field(0, "weekday", 4))
+        );
+        assert_eq!(
+            FirstDate::parse_with("23 Mai 2025", "%d %B %Y"),
+            Err(ParseError::new(
+                3,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'M',
+                    expected: "month name"
+                }
+            ))
+        );
+        assert_eq!(
+            FirstDate::parse_with("2025-05-23 Mars/Olympus_Mons", "%Y-%m-%d %Z"),
+            Err(ParseError::new(
+                11,
+                ParseErrorKind::UnknownTimezone(String::from("Mars/Olympus_Mons"))
+            ))
+        );
+        assert_eq!(
+            FirstDate::parse_with("2025-05-23 extra", "%Y-%m-%d"),
+            Err(ParseError::new(10, ParseErrorKind::TrailingCharacters))
+        );
+        assert_eq!(
+            FirstDate::parse_with("+1000000-01-01", "%Y-%m-%d"),
+            Err(ParseError::invalid_field(0, "year", 1_000_000))
+        );
+        assert!(FirstDate::parse_with("13:00 PM", "%I:%M %p").is_err());
+        assert!(FirstDate::parse_with("2025-05-23", "%Y-%Q").is_err());
+    }
+
+    #[test]
+    fn parse_with_locale_positive() {
+        let expected = FirstDate::from_ymd_hms(2025, 5, 23, 15, 46, 48).unwrap();
+        assert_eq!(
+            FirstDate::parse_with_locale(
+                "vendredi 23 mai 2025 15:46:48",
+                "%A %d %B %Y %T",
+                &Locale::FR
+            ),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            FirstDate::parse_with_locale(
+                "ПТ, 23 МАЙ 2025 15:46:48",
+                "%a, %d %b %Y %T",
+                &Locale::RU
+            ),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            FirstDate::parse_with_locale(
+                "23 mayo 2025 3:46:48 p. m.",
+                "%d %B %Y %I:%M:%S %p",
+                &Locale::ES
+            ),
+            Ok(expected.clone())
+        );
+
+        // every locale reads what it writes
+        for locale in Locale::ALL {
+            for pattern in [
+                locale.date_time_pattern,
+                "%a %d %b %Y %I:%M:%S %p",
+                "%A %d %B %Y %H:%M:%S",
+            ] {
+                let formatted = expected.format_with_locale(pattern, locale);
+                assert_eq!(
+                    FirstDate::parse_with_locale(&formatted, pattern, locale),
+                    Ok(expected.clone()),
+                    "{} {}",
+                    locale.code,
+                    formatted
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_with_locale_negative() {
+        assert_eq!(
+            FirstDate::parse_with_locale("vendredi 23 Mai 2025", "%A %d %B %Y", &Locale::DE),
+            Err(ParseError::new(
+                0,
+                ParseErrorKind::UnexpectedCharacter {
+                    found: 'v',
+                    expected: "weekday name"
+                }
+            ))
+        );
+        assert_eq!(
+            FirstDate::parse_with_locale("Donnerstag 23 Mai 2025", "%A %d %B %Y", &Locale::DE),
+            Err(ParseError::invalid_field(0, "weekday", 4))
+        );
+    }
+}
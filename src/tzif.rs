@@ -0,0 +1,692 @@
+use crate::date_utilities::{days_from_civil, days_in_month, is_leap_year, EPOCH_DAY};
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+/// The directory where compiled timezone (TZif) files live on most Unix systems.
+pub const ZONEINFO_DIRECTORY: &str = "/usr/share/zoneinfo";
+
+const TZIF_MAGIC: &[u8; 4] = b"TZif";
+const HEADER_LENGTH: usize = 44;
+const SECONDS_IN_HOUR: i64 = 3600;
+
+/// A single entry of the transition table: starting at `time` (seconds since the Unix epoch) the local time type at `local_time_type` applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transition {
+    pub time: i64,
+    pub local_time_type: usize,
+}
+
+/// A local time type, for example "CDT" which is 5 hours behind UTC and is daylight savings time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalTimeType {
+    pub utc_offset: i32,
+    pub is_dst: bool,
+    pub abbreviation: String,
+}
+
+/// A leap second record: starting at `time` the total correction applied is `correction` seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeapSecond {
+    pub time: i64,
+    pub correction: i32,
+}
+
+/// The contents of a TZif (version 1, 2 or 3) file as described by RFC 8536.
+#[derive(Debug, Clone)]
+pub struct TimeZoneInfo {
+    pub version: u8,
+    pub transitions: Vec<Transition>,
+    pub local_time_types: Vec<LocalTimeType>,
+    pub leap_seconds: Vec<LeapSecond>,
+    /// The POSIX TZ string found at the end of version 2+ files. Used for instants after the last transition.
+    pub footer: Option<String>,
+    rule: Option<PosixTz>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn` - day 1 to 365, February 29th is never counted.
+    JulianNoLeap(u16),
+    /// `n` - day 0 to 365, February 29th is counted in leap years.
+    Julian(u16),
+    /// `Mm.w.d` - day `d` (0 is Sunday) of week `w` (5 is the last week) of month `m`.
+    MonthWeekDay(u8, u8, u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DstRule {
+    daylight: LocalTimeType,
+    start: RuleDate,
+    start_time: i64,
+    end: RuleDate,
+    end_time: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PosixTz {
+    standard: LocalTimeType,
+    dst: Option<DstRule>,
+}
+
+struct Counts {
+    is_ut: usize,
+    is_std: usize,
+    leap: usize,
+    time: usize,
+    kind: usize,
+    chars: usize,
+}
+
+impl TimeZoneInfo {
+    /// Reads the compiled zone data for a timezone name (for example "America/Chicago") from `/usr/share/zoneinfo`.
+    pub fn for_zone(timezone_name: &str) -> Result<TimeZoneInfo, Error> {
+        TimeZoneInfo::for_zone_in(Path::new(ZONEINFO_DIRECTORY), timezone_name)
+    }
+
+    /// Same as `for_zone` but looks in the provided zoneinfo directory.
+    pub fn for_zone_in(directory: &Path, timezone_name: &str) -> Result<TimeZoneInfo, Error> {
+        if timezone_name.is_empty()
+            || timezone_name.starts_with('/')
+            || timezone_name.split('/').any(|part| part == "..")
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid timezone name: {}", timezone_name),
+            ));
+        }
+
+        TimeZoneInfo::from_file(&directory.join(timezone_name))
+    }
+
+    /// Reads and parses a TZif file.
+    pub fn from_file(path: &Path) -> Result<TimeZoneInfo, Error> {
+        let bytes = fs::read(path)?;
+        TimeZoneInfo::from_bytes(&bytes)
+    }
+
+    /// Parses the raw contents of a TZif file. For version 2+ files the 64-bit data block and the footer are used.
+    pub fn from_bytes(bytes: &[u8]) -> Result<TimeZoneInfo, Error> {
+        let (version, counts) = read_header(bytes)?;
+        let v1_length = data_block_length(&counts, 4);
+        if version == 1 {
+            let mut zone = read_data_block(&bytes[HEADER_LENGTH..], &counts, 4)?;
+            zone.version = version;
+            return Ok(zone);
+        }
+
+        // skip the legacy 32-bit block, the 64-bit block has its own header
+        let v2_start = HEADER_LENGTH + v1_length;
+        let (_, v2_counts) = read_header(bytes.get(v2_start..).unwrap_or_default())?;
+        let v2_data_start = v2_start + HEADER_LENGTH;
+        let mut zone = read_data_block(&bytes[v2_data_start..], &v2_counts, 8)?;
+        zone.version = version;
+
+        let footer_start = v2_data_start + data_block_length(&v2_counts, 8);
+        zone.footer = read_footer(&bytes[footer_start..])?;
+        if let Some(footer) = &zone.footer {
+            if !footer.is_empty() {
+                zone.rule = Some(parse_posix_tz(footer)?);
+            }
+        }
+
+        Ok(zone)
+    }
+
+    /// Returns the local time type in effect at the provided number of seconds since the Unix epoch.
+    pub fn local_time_type_at(&self, epoch_seconds: i64) -> LocalTimeType {
+        let index = self
+            .transitions
+            .partition_point(|transition| transition.time <= epoch_seconds);
+
+        if index == self.transitions.len() {
+            if let Some(rule) = &self.rule {
+                return rule.local_time_type_at(epoch_seconds);
+            }
+        }
+
+        if index == 0 {
+            // before the first transition (or no transitions at all) the first local time type applies
+            return self.local_time_types[0].clone();
+        }
+
+        let transition = &self.transitions[index - 1];
+        self.local_time_types[transition.local_time_type].clone()
+    }
+
+    /// Indicates if daylight savings time is in effect at the provided number of seconds since the Unix epoch.
+    pub fn is_dst_at(&self, epoch_seconds: i64) -> bool {
+        self.local_time_type_at(epoch_seconds).is_dst
+    }
+
+    /// The offset from UTC (in seconds) in effect at the provided number of seconds since the Unix epoch.
+    pub fn utc_offset_at(&self, epoch_seconds: i64) -> i32 {
+        self.local_time_type_at(epoch_seconds).utc_offset
+    }
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Invalid TZif data: {}", message),
+    )
+}
+
+fn read_header(bytes: &[u8]) -> Result<(u8, Counts), Error> {
+    if bytes.len() < HEADER_LENGTH {
+        return Err(invalid_data("header is truncated"));
+    }
+    if &bytes[0..4] != TZIF_MAGIC {
+        return Err(invalid_data("missing TZif magic"));
+    }
+
+    let version = match bytes[4] {
+        0 => 1,
+        b'2' => 2,
+        b'3' => 3,
+        b'4' => 4,
+        _ => return Err(invalid_data("unsupported version")),
+    };
+
+    // six big-endian counts follow the magic, the version and 15 reserved bytes
+    let count = |index: usize| read_u32(&bytes[20 + index * 4..]) as usize;
+    let counts = Counts {
+        is_ut: count(0),
+        is_std: count(1),
+        leap: count(2),
+        time: count(3),
+        kind: count(4),
+        chars: count(5),
+    };
+
+    if counts.kind == 0 {
+        return Err(invalid_data("no local time types"));
+    }
+    if counts.is_ut != 0 && counts.is_ut != counts.kind {
+        return Err(invalid_data(
+            "UT indicator count does not match the type count",
+        ));
+    }
+    if counts.is_std != 0 && counts.is_std != counts.kind {
+        return Err(invalid_data(
+            "standard indicator count does not match the type count",
+        ));
+    }
+
+    Ok((version, counts))
+}
+
+fn data_block_length(counts: &Counts, time_size: usize) -> usize {
+    counts.time * time_size
+        + counts.time
+        + counts.kind * 6
+        + counts.chars
+        + counts.leap * (time_size + 4)
+        + counts.is_std
+        + counts.is_ut
+}
+
+fn read_data_block(bytes: &[u8], counts: &Counts, time_size: usize) -> Result<TimeZoneInfo, Error> {
+    if bytes.len() < data_block_length(counts, time_size) {
+        return Err(invalid_data("data block is truncated"));
+    }
+
+    let read_time = |offset: usize| -> i64 {
+        if time_size == 4 {
+            read_u32(&bytes[offset..]) as i32 as i64
+        } else {
+            read_u64(&bytes[offset..]) as i64
+        }
+    };
+
+    let indices_start = counts.time * time_size;
+    let types_start = indices_start + counts.time;
+    let chars_start = types_start + counts.kind * 6;
+    let leap_start = chars_start + counts.chars;
+
+    let mut transitions = Vec::with_capacity(counts.time);
+    for index in 0..counts.time {
+        let local_time_type = bytes[indices_start + index] as usize;
+        if local_time_type >= counts.kind {
+            return Err(invalid_data(
+                "transition refers to an unknown local time type",
+            ));
+        }
+        transitions.push(Transition {
+            time: read_time(index * time_size),
+            local_time_type,
+        });
+    }
+
+    let designations = &bytes[chars_start..leap_start];
+    let mut local_time_types = Vec::with_capacity(counts.kind);
+    for index in 0..counts.kind {
+        let record = &bytes[types_start + index * 6..];
+        let designation_index = record[5] as usize;
+        if designation_index >= designations.len() {
+            return Err(invalid_data("designation index out of range"));
+        }
+        let designation = &designations[designation_index..];
+        let designation_end = designation
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(designation.len());
+
+        local_time_types.push(LocalTimeType {
+            utc_offset: read_u32(record) as i32,
+            is_dst: record[4] == 1,
+            abbreviation: String::from_utf8_lossy(&designation[..designation_end]).into_owned(),
+        });
+    }
+
+    let mut leap_seconds = Vec::with_capacity(counts.leap);
+    for index in 0..counts.leap {
+        let offset = leap_start + index * (time_size + 4);
+        leap_seconds.push(LeapSecond {
+            time: read_time(offset),
+            correction: read_u32(&bytes[offset + time_size..]) as i32,
+        });
+    }
+
+    Ok(TimeZoneInfo {
+        version: 1,
+        transitions,
+        local_time_types,
+        leap_seconds,
+        footer: None,
+        rule: None,
+    })
+}
+
+fn read_footer(bytes: &[u8]) -> Result<Option<String>, Error> {
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    if bytes[0] != b'\n' {
+        return Err(invalid_data("footer does not start with a newline"));
+    }
+
+    let footer_end = match bytes[1..].iter().position(|byte| *byte == b'\n') {
+        Some(position) => position + 1,
+        None => return Err(invalid_data("footer does not end with a newline")),
+    };
+
+    match std::str::from_utf8(&bytes[1..footer_end]) {
+        Ok(footer) => Ok(Some(String::from(footer))),
+        Err(_) => Err(invalid_data("footer is not valid text")),
+    }
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buffer = [0; 8];
+    buffer.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(buffer)
+}
+
+impl PosixTz {
+    fn local_time_type_at(&self, epoch_seconds: i64) -> LocalTimeType {
+        let rule = match &self.dst {
+            Some(rule) => rule,
+            None => return self.standard.clone(),
+        };
+
+        // the rules are expressed in local time, so find the year using standard time
+        let local_seconds = epoch_seconds + self.standard.utc_offset as i64;
+        let year = year_of_epoch_seconds(local_seconds);
+
+        let start = rule.start.epoch_day(year) * EPOCH_DAY as i64 + rule.start_time
+            - self.standard.utc_offset as i64;
+        let end = rule.end.epoch_day(year) * EPOCH_DAY as i64 + rule.end_time
+            - rule.daylight.utc_offset as i64;
+
+        let is_dst = if start < end {
+            start <= epoch_seconds && epoch_seconds < end
+        } else {
+            // southern hemisphere, daylight savings time spans the new year
+            !(end <= epoch_seconds && epoch_seconds < start)
+        };
+
+        if is_dst {
+            rule.daylight.clone()
+        } else {
+            self.standard.clone()
+        }
+    }
+}
+
+impl RuleDate {
+    /// Days since the Unix epoch of the rule's date in the provided year.
+    fn epoch_day(&self, year: i64) -> i64 {
+        let january_first = days_from_civil(year, 1, 1);
+        match *self {
+            RuleDate::JulianNoLeap(day) => {
+                let leap_day = if is_leap_year(year) && day >= 60 {
+                    1
+                } else {
+                    0
+                };
+                january_first + day as i64 - 1 + leap_day
+            }
+            RuleDate::Julian(day) => january_first + day as i64,
+            RuleDate::MonthWeekDay(month, week, weekday) => {
+                let first_of_month = days_from_civil(year, month, 1);
+                // January 1st 1970 was a Thursday (4)
+                let first_weekday = (first_of_month + 4).rem_euclid(7);
+                let mut day =
+                    (weekday as i64 - first_weekday).rem_euclid(7) + (week as i64 - 1) * 7;
+
+                while day >= days_in_month(year, month) as i64 {
+                    day -= 7;
+                }
+
+                first_of_month + day
+            }
+        }
+    }
+}
+
+fn year_of_epoch_seconds(epoch_seconds: i64) -> i64 {
+    let seconds_in_average_year = 31_556_952;
+    let mut year = 1970 + epoch_seconds.div_euclid(seconds_in_average_year);
+    while days_from_civil(year, 1, 1) * EPOCH_DAY as i64 > epoch_seconds {
+        year -= 1;
+    }
+    while days_from_civil(year + 1, 1, 1) * EPOCH_DAY as i64 <= epoch_seconds {
+        year += 1;
+    }
+    year
+}
+
+fn parse_posix_tz(tz: &str) -> Result<PosixTz, Error> {
+    let invalid = || invalid_data(&format!("unsupported TZ string \"{}\"", tz));
+    let mut rest = tz;
+
+    let standard_name = parse_tz_name(&mut rest).ok_or_else(invalid)?;
+    // POSIX offsets are positive west of Greenwich, the opposite of TZif offsets
+    let standard_offset = -parse_tz_time(&mut rest).ok_or_else(invalid)?;
+    let standard = LocalTimeType {
+        utc_offset: standard_offset as i32,
+        is_dst: false,
+        abbreviation: standard_name,
+    };
+
+    if rest.is_empty() {
+        return Ok(PosixTz {
+            standard,
+            dst: None,
+        });
+    }
+
+    let daylight_name = parse_tz_name(&mut rest).ok_or_else(invalid)?;
+    let daylight_offset = if rest.starts_with(',') {
+        standard_offset + SECONDS_IN_HOUR
+    } else {
+        -parse_tz_time(&mut rest).ok_or_else(invalid)?
+    };
+
+    let parse_rule = |rest: &mut &str| -> Option<(RuleDate, i64)> {
+        *rest = rest.strip_prefix(',')?;
+        let date = parse_rule_date(rest)?;
+        let time = match rest.strip_prefix('/') {
+            Some(remaining) => {
+                *rest = remaining;
+                parse_tz_time(rest)?
+            }
+            None => 2 * SECONDS_IN_HOUR,
+        };
+        Some((date, time))
+    };
+
+    let (start, start_time) = parse_rule(&mut rest).ok_or_else(invalid)?;
+    let (end, end_time) = parse_rule(&mut rest).ok_or_else(invalid)?;
+    if !rest.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(PosixTz {
+        standard,
+        dst: Some(DstRule {
+            daylight: LocalTimeType {
+                utc_offset: daylight_offset as i32,
+                is_dst: true,
+                abbreviation: daylight_name,
+            },
+            start,
+            start_time,
+            end,
+            end_time,
+        }),
+    })
+}
+
+/// Parses either an alphabetic name ("CST") or a quoted name ("<+0530>").
+fn parse_tz_name(rest: &mut &str) -> Option<String> {
+    let (name, remaining) = if let Some(quoted) = rest.strip_prefix('<') {
+        let end = quoted.find('>')?;
+        (&quoted[..end], &quoted[end + 1..])
+    } else {
+        let end = rest
+            .find(|character: char| !character.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        (&rest[..end], &rest[end..])
+    };
+
+    if name.len() < 3 {
+        return None;
+    }
+    *rest = remaining;
+    Some(String::from(name))
+}
+
+/// Parses `[+-]hh[:mm[:ss]]` into seconds.
+fn parse_tz_time(rest: &mut &str) -> Option<i64> {
+    let mut sign = 1;
+    if let Some(remaining) = rest.strip_prefix('-') {
+        sign = -1;
+        *rest = remaining;
+    } else if let Some(remaining) = rest.strip_prefix('+') {
+        *rest = remaining;
+    }
+
+    let mut seconds = 0;
+    for (index, multiplier) in [SECONDS_IN_HOUR, 60, 1].iter().enumerate() {
+        if index > 0 {
+            match rest.strip_prefix(':') {
+                Some(remaining) => *rest = remaining,
+                None => break,
+            }
+        }
+        seconds += parse_tz_number(rest)? * multiplier;
+    }
+
+    Some(sign * seconds)
+}
+
+fn parse_rule_date(rest: &mut &str) -> Option<RuleDate> {
+    if let Some(remaining) = rest.strip_prefix('J') {
+        *rest = remaining;
+        let day = parse_tz_number(rest)?;
+        return (1..=365)
+            .contains(&day)
+            .then_some(RuleDate::JulianNoLeap(day as u16));
+    }
+
+    if let Some(remaining) = rest.strip_prefix('M') {
+        *rest = remaining;
+        let month = parse_tz_number(rest)?;
+        *rest = rest.strip_prefix('.')?;
+        let week = parse_tz_number(rest)?;
+        *rest = rest.strip_prefix('.')?;
+        let weekday = parse_tz_number(rest)?;
+        let is_valid =
+            (1..=12).contains(&month) && (1..=5).contains(&week) && (0..=6).contains(&weekday);
+        return is_valid.then_some(RuleDate::MonthWeekDay(
+            month as u8,
+            week as u8,
+            weekday as u8,
+        ));
+    }
+
+    let day = parse_tz_number(rest)?;
+    (0..=365)
+        .contains(&day)
+        .then_some(RuleDate::Julian(day as u16))
+}
+
+fn parse_tz_number(rest: &mut &str) -> Option<i64> {
+    let end = rest
+        .find(|character: char| !character.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let number = rest[..end].parse::<i64>().ok()?;
+    *rest = &rest[end..];
+    Some(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHICAGO: &[u8] = include_bytes!("../resources/tzif/America/Chicago");
+    const CHICAGO_V1: &[u8] = include_bytes!("../resources/tzif/America_Chicago_v1");
+    const JERUSALEM: &[u8] = include_bytes!("../resources/tzif/Asia/Jerusalem");
+    const KOLKATA: &[u8] = include_bytes!("../resources/tzif/Asia/Kolkata");
+    const SYDNEY: &[u8] = include_bytes!("../resources/tzif/Australia/Sydney");
+
+    // 2025-05-23T03:46:48Z
+    const MAY_2025: i64 = 1747972008;
+    // 2025-01-15T12:00:00Z
+    const JANUARY_2025: i64 = 1736942400;
+
+    #[test]
+    fn from_bytes_v2_positive() {
+        let zone = TimeZoneInfo::from_bytes(CHICAGO).unwrap();
+        assert_eq!(zone.version, 2);
+        assert_eq!(zone.footer.as_deref(), Some("CST6CDT,M3.2.0,M11.1.0"));
+        assert!(!zone.transitions.is_empty());
+        assert!(zone
+            .local_time_types
+            .iter()
+            .any(|local_time_type| local_time_type.abbreviation == "CDT"));
+    }
+
+    #[test]
+    fn from_bytes_v1_positive() {
+        let zone = TimeZoneInfo::from_bytes(CHICAGO_V1).unwrap();
+        assert_eq!(zone.version, 1);
+        assert_eq!(zone.footer, None);
+
+        let daylight = zone.local_time_type_at(MAY_2025);
+        assert_eq!(daylight.utc_offset, -5 * 3600);
+        assert!(daylight.is_dst);
+        assert_eq!(daylight.abbreviation, "CDT");
+    }
+
+    #[test]
+    fn from_bytes_v3_positive() {
+        let zone = TimeZoneInfo::from_bytes(JERUSALEM).unwrap();
+        assert_eq!(zone.version, 3);
+        assert_eq!(zone.footer.as_deref(), Some("IST-2IDT,M3.4.4/26,M10.5.0"));
+        assert_eq!(zone.utc_offset_at(JANUARY_2025), 2 * 3600);
+        assert_eq!(zone.utc_offset_at(MAY_2025), 3 * 3600);
+    }
+
+    #[test]
+    fn from_bytes_bad_magic_negative() {
+        let mut bytes = CHICAGO.to_vec();
+        bytes[0] = b'X';
+        assert_eq!(
+            TimeZoneInfo::from_bytes(&bytes).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn from_bytes_truncated_negative() {
+        assert!(TimeZoneInfo::from_bytes(&CHICAGO[..100]).is_err());
+        assert!(TimeZoneInfo::from_bytes(&CHICAGO[..20]).is_err());
+    }
+
+    #[test]
+    fn is_dst_at_positive() {
+        let zone = TimeZoneInfo::from_bytes(CHICAGO).unwrap();
+        assert!(zone.is_dst_at(MAY_2025));
+        assert!(!zone.is_dst_at(JANUARY_2025));
+        assert_eq!(zone.utc_offset_at(JANUARY_2025), -6 * 3600);
+    }
+
+    #[test]
+    fn is_dst_at_transition_boundary() {
+        let zone = TimeZoneInfo::from_bytes(CHICAGO).unwrap();
+        // DST started 2025-03-09 at 2:00 CST which is 08:00 UTC
+        let transition: i64 = 1741507200;
+        assert!(!zone.is_dst_at(transition - 1));
+        assert!(zone.is_dst_at(transition));
+    }
+
+    #[test]
+    fn half_hour_offset_positive() {
+        let zone = TimeZoneInfo::from_bytes(KOLKATA).unwrap();
+        let local_time_type = zone.local_time_type_at(MAY_2025);
+        assert_eq!(local_time_type.utc_offset, 5 * 3600 + 30 * 60);
+        assert!(!local_time_type.is_dst);
+        assert_eq!(local_time_type.abbreviation, "IST");
+    }
+
+    #[test]
+    fn footer_rule_after_last_transition() {
+        let zone = TimeZoneInfo::from_bytes(CHICAGO).unwrap();
+        // 2100-07-01T12:00:00Z and 2100-12-01T12:00:00Z, past the end of the transition table
+        assert!(zone.is_dst_at(4118140800));
+        assert!(!zone.is_dst_at(4131604800));
+    }
+
+    #[test]
+    fn footer_rule_southern_hemisphere() {
+        let zone = TimeZoneInfo::from_bytes(SYDNEY).unwrap();
+        // 2100-01-15T00:00:00Z and 2100-07-15T00:00:00Z
+        assert_eq!(zone.utc_offset_at(4103654400), 11 * 3600);
+        assert_eq!(zone.utc_offset_at(4119292800), 10 * 3600);
+    }
+
+    #[test]
+    fn parse_posix_tz_positive() {
+        let rule = parse_posix_tz("<+0530>-5:30").unwrap();
+        assert_eq!(rule.standard.utc_offset, 5 * 3600 + 30 * 60);
+        assert_eq!(rule.standard.abbreviation, "+0530");
+        assert_eq!(rule.dst, None);
+
+        let rule = parse_posix_tz("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let dst = rule.dst.unwrap();
+        assert_eq!(dst.daylight.utc_offset, -4 * 3600);
+        assert_eq!(dst.start, RuleDate::MonthWeekDay(3, 2, 0));
+        assert_eq!(dst.start_time, 2 * 3600);
+    }
+
+    #[test]
+    fn parse_posix_tz_negative() {
+        assert!(parse_posix_tz("").is_err());
+        assert!(parse_posix_tz("EST5EDT,M13.2.0,M11.1.0").is_err());
+        assert!(parse_posix_tz("EST5EDT,M3.2.0").is_err());
+    }
+
+    #[test]
+    fn rule_date_last_week_of_month() {
+        // the last Sunday of October 2025 is the 26th
+        let day = RuleDate::MonthWeekDay(10, 5, 0).epoch_day(2025);
+        assert_eq!(day, days_from_civil(2025, 10, 26));
+
+        // J60 is always March 1st, 59 is March 1st only in leap years
+        assert_eq!(
+            RuleDate::JulianNoLeap(60).epoch_day(2024),
+            days_from_civil(2024, 3, 1)
+        );
+        assert_eq!(
+            RuleDate::Julian(59).epoch_day(2024),
+            days_from_civil(2024, 2, 29)
+        );
+    }
+}
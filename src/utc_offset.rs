@@ -0,0 +1,192 @@
+use crate::error::FirstDateError;
+use std::fmt;
+
+const SECONDS_IN_MINUTE: i32 = 60;
+const SECONDS_IN_HOUR: i32 = 3600;
+/// Offsets must be strictly less than a day in either direction.
+const MAX_OFFSET_SECONDS: i32 = 24 * SECONDS_IN_HOUR - 1;
+
+/// An offset from UTC with second granularity, for example `+05:30` for "Asia/Kolkata" or `-06:00` for "America/Chicago".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct UtcOffset {
+    seconds: i32,
+}
+
+impl UtcOffset {
+    /// The offset of UTC itself (`+00:00`).
+    pub const UTC: UtcOffset = UtcOffset { seconds: 0 };
+
+    /// Creates an offset from a number of seconds east of UTC. Returns `None` if the offset is a day or more.
+    pub fn from_seconds(seconds: i32) -> Option<UtcOffset> {
+        if seconds.unsigned_abs() > MAX_OFFSET_SECONDS as u32 {
+            return None;
+        }
+        Some(UtcOffset { seconds })
+    }
+
+    /// Creates an offset from hours, minutes and seconds, for example `from_hms(5, 30, 0)` is `+05:30` and `from_hms(-3, -30, 0)` is `-03:30`. All the non-zero parts must have the same sign.
+    pub fn from_hms(hours: i8, minutes: i8, seconds: i8) -> Option<UtcOffset> {
+        let parts = [hours, minutes, seconds];
+        let has_positive = parts.iter().any(|part| *part > 0);
+        let has_negative = parts.iter().any(|part| *part < 0);
+        if has_positive && has_negative
+            || minutes.unsigned_abs() >= 60
+            || seconds.unsigned_abs() >= 60
+        {
+            return None;
+        }
+
+        UtcOffset::from_seconds(
+            hours as i32 * SECONDS_IN_HOUR + minutes as i32 * SECONDS_IN_MINUTE + seconds as i32,
+        )
+    }
+
+    /// The total offset in seconds (positive east of UTC).
+    pub fn whole_seconds(&self) -> i32 {
+        self.seconds
+    }
+
+    /// The total offset in minutes, truncated toward zero.
+    pub fn whole_minutes(&self) -> i32 {
+        self.seconds / SECONDS_IN_MINUTE
+    }
+
+    /// The total offset in hours, truncated toward zero. For example `+05:30` is 5.
+    pub fn whole_hours(&self) -> i32 {
+        self.seconds / SECONDS_IN_HOUR
+    }
+
+    /// The minutes past the whole hour, with the sign of the offset. For example `-03:30` is -30.
+    pub fn minutes_past_hour(&self) -> i32 {
+        (self.seconds % SECONDS_IN_HOUR) / SECONDS_IN_MINUTE
+    }
+
+    pub fn is_utc(&self) -> bool {
+        self.seconds == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.seconds < 0
+    }
+}
+
+/// Displays as `+hh:mm`, or `+hh:mm:ss` when the offset is not a whole number of minutes.
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.is_negative() { '-' } else { '+' };
+        let seconds = self.seconds.abs();
+        write!(
+            f,
+            "{}{:02}:{:02}",
+            sign,
+            seconds / SECONDS_IN_HOUR,
+            (seconds % SECONDS_IN_HOUR) / SECONDS_IN_MINUTE
+        )?;
+        if seconds % SECONDS_IN_MINUTE != 0 {
+            write!(f, ":{:02}", seconds % SECONDS_IN_MINUTE)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses `+hh:mm`, `-hh:mm`, `+hh:mm:ss` or a bare number of hours such as `-6`.
+impl std::str::FromStr for UtcOffset {
+    type Err = FirstDateError;
+
+    fn from_str(value: &str) -> Result<UtcOffset, FirstDateError> {
+        let invalid = || FirstDateError::Parse(format!("Invalid UTC offset: {}", value));
+        let (is_negative, digits) = match value.as_bytes().first() {
+            Some(b'-') => (true, &value[1..]),
+            Some(b'+') => (false, &value[1..]),
+            _ => (false, value),
+        };
+
+        let mut parts = [0i8; 3];
+        for (part_count, part) in digits.split(':').enumerate() {
+            if part_count == parts.len()
+                || part.is_empty()
+                || !part.bytes().all(|b| b.is_ascii_digit())
+            {
+                return Err(invalid());
+            }
+            parts[part_count] = part.parse::<i8>().map_err(|_| invalid())?;
+        }
+
+        if is_negative {
+            parts = parts.map(|part| -part);
+        }
+        UtcOffset::from_hms(parts[0], parts[1], parts[2]).ok_or_else(invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_positive() {
+        assert_eq!(UtcOffset::from_hms(5, 30, 0).unwrap().to_string(), "+05:30");
+        assert_eq!(UtcOffset::from_hms(5, 45, 0).unwrap().to_string(), "+05:45");
+        assert_eq!(
+            UtcOffset::from_hms(-3, -30, 0).unwrap().to_string(),
+            "-03:30"
+        );
+        assert_eq!(UtcOffset::UTC.to_string(), "+00:00");
+        assert_eq!(
+            UtcOffset::from_seconds(-20).unwrap().to_string(),
+            "-00:00:20"
+        );
+    }
+
+    #[test]
+    fn from_hms_negative() {
+        assert_eq!(UtcOffset::from_hms(5, -30, 0), None);
+        assert_eq!(UtcOffset::from_hms(5, 60, 0), None);
+        assert_eq!(UtcOffset::from_hms(24, 0, 0), None);
+        assert_eq!(UtcOffset::from_hms(0, i8::MIN, 0), None);
+        assert_eq!(UtcOffset::from_hms(0, 0, i8::MIN), None);
+        assert_eq!(UtcOffset::from_hms(i8::MIN, i8::MIN, i8::MIN), None);
+    }
+
+    #[test]
+    fn from_seconds_negative() {
+        assert_eq!(UtcOffset::from_seconds(86400), None);
+        assert_eq!(UtcOffset::from_seconds(-86400), None);
+        assert_eq!(UtcOffset::from_seconds(i32::MIN), None);
+        assert_eq!(UtcOffset::from_seconds(i32::MAX), None);
+    }
+
+    #[test]
+    fn accessors_positive() {
+        let offset = UtcOffset::from_hms(-9, -30, 0).unwrap();
+        assert_eq!(offset.whole_seconds(), -34200);
+        assert_eq!(offset.whole_minutes(), -570);
+        assert_eq!(offset.whole_hours(), -9);
+        assert_eq!(offset.minutes_past_hour(), -30);
+        assert!(offset.is_negative());
+    }
+
+    #[test]
+    fn from_str_positive() {
+        assert_eq!(
+            "+12:45".parse::<UtcOffset>(),
+            Ok(UtcOffset::from_hms(12, 45, 0).unwrap())
+        );
+        assert_eq!(
+            "-06:00".parse::<UtcOffset>(),
+            Ok(UtcOffset::from_hms(-6, 0, 0).unwrap())
+        );
+        assert_eq!(
+            "-6".parse::<UtcOffset>(),
+            Ok(UtcOffset::from_hms(-6, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn from_str_negative() {
+        assert!("".parse::<UtcOffset>().is_err());
+        assert!("+05:3x".parse::<UtcOffset>().is_err());
+        assert!("+05:30:00:00".parse::<UtcOffset>().is_err());
+        assert!("+25:00".parse::<UtcOffset>().is_err());
+    }
+}
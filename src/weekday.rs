@@ -0,0 +1,142 @@
+use crate::error::FirstDateError;
+use std::fmt;
+
+/// A day of the week. Weeks start on Monday like in ISO 8601.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All the weekdays, starting on Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// The weekday of a number of days since Jan 1 1970 (which was a Thursday). Negative days are before 1970.
+    pub fn from_days_since_epoch(days: i64) -> Weekday {
+        Weekday::ALL[(days + 3).rem_euclid(7) as usize]
+    }
+
+    /// The weekday of an ISO 8601 weekday number, 1 is Monday and 7 is Sunday. Returns `None` for any other number.
+    pub fn from_number_from_monday(number: u8) -> Option<Weekday> {
+        Weekday::ALL.get((number as usize).checked_sub(1)?).copied()
+    }
+
+    /// The ISO 8601 weekday number, 1 for Monday to 7 for Sunday.
+    pub fn number_from_monday(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// 1 for Sunday to 7 for Saturday, the US convention.
+    pub fn number_from_sunday(&self) -> u8 {
+        (*self as u8 + 1) % 7 + 1
+    }
+
+    /// The next day of the week, Sunday is followed by Monday.
+    pub fn succ(&self) -> Weekday {
+        Weekday::ALL[(*self as usize + 1) % 7]
+    }
+
+    /// The previous day of the week, Monday is preceded by Sunday.
+    pub fn pred(&self) -> Weekday {
+        Weekday::ALL[(*self as usize + 6) % 7]
+    }
+
+    /// The number of days (0 to 6) from `other` forward to this weekday, for example Friday is 4 days after Monday.
+    pub fn days_since(&self, other: Weekday) -> u8 {
+        (*self as u8 + 7 - other as u8) % 7
+    }
+
+    /// The english name, for example "Tuesday".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+
+    /// The first three letters of the english name, for example "Tue".
+    pub fn abbreviation(&self) -> &'static str {
+        &self.name()[..3]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the english name or its three letter abbreviation, ignoring case.
+impl std::str::FromStr for Weekday {
+    type Err = FirstDateError;
+
+    fn from_str(value: &str) -> Result<Weekday, FirstDateError> {
+        Weekday::ALL
+            .into_iter()
+            .find(|weekday| {
+                weekday.name().eq_ignore_ascii_case(value)
+                    || weekday.abbreviation().eq_ignore_ascii_case(value)
+            })
+            .ok_or_else(|| FirstDateError::Parse(format!("Invalid weekday: {}", value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_days_since_epoch_positive() {
+        assert_eq!(Weekday::from_days_since_epoch(0), Weekday::Thursday);
+        assert_eq!(Weekday::from_days_since_epoch(-1), Weekday::Wednesday);
+        assert_eq!(Weekday::from_days_since_epoch(-4), Weekday::Sunday);
+        // 2025-05-23
+        assert_eq!(Weekday::from_days_since_epoch(20231), Weekday::Friday);
+    }
+
+    #[test]
+    fn numbers_positive() {
+        assert_eq!(Weekday::Monday.number_from_monday(), 1);
+        assert_eq!(Weekday::Sunday.number_from_monday(), 7);
+        assert_eq!(Weekday::Sunday.number_from_sunday(), 1);
+        assert_eq!(Weekday::Saturday.number_from_sunday(), 7);
+        assert_eq!(Weekday::from_number_from_monday(5), Some(Weekday::Friday));
+        assert_eq!(Weekday::from_number_from_monday(0), None);
+        assert_eq!(Weekday::from_number_from_monday(8), None);
+    }
+
+    #[test]
+    fn succ_pred_positive() {
+        assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+        assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+        assert_eq!(Weekday::Friday.days_since(Weekday::Monday), 4);
+        assert_eq!(Weekday::Monday.days_since(Weekday::Friday), 3);
+    }
+
+    #[test]
+    fn from_str_positive() {
+        assert_eq!("tuesday".parse::<Weekday>(), Ok(Weekday::Tuesday));
+        assert_eq!("SAT".parse::<Weekday>(), Ok(Weekday::Saturday));
+        assert!("Tues".parse::<Weekday>().is_err());
+        assert_eq!(Weekday::Wednesday.to_string(), "Wednesday");
+    }
+}